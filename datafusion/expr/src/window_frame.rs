@@ -24,9 +24,13 @@
 //! - An EXCLUDE clause.
 
 use crate::{expr::Sort, lit};
+use std::cmp::Ordering;
 use std::fmt::{self, Formatter};
-use std::hash::Hash;
+use std::hash::{Hash, Hasher};
+use std::ops::Range;
+use std::str::FromStr;
 
+use arrow::datatypes::DataType;
 use datafusion_common::{Result, ScalarValue, plan_err};
 #[cfg(feature = "sql")]
 use sqlparser::ast::{self, ValueWithSpan};
@@ -35,7 +39,13 @@ use sqlparser::ast::{self, ValueWithSpan};
 /// window function. The ending frame boundary can be omitted if the `BETWEEN`
 /// and `AND` keywords that surround the starting frame boundary are also omitted,
 /// in which case the ending frame boundary defaults to `CURRENT ROW`.
-#[derive(Clone, PartialEq, Eq, PartialOrd, Hash)]
+///
+/// The `causal` flag is derived from the other fields, and therefore does not
+/// take part in comparisons. When the `serde` feature is enabled, it is not
+/// trusted when deserializing either; it is recomputed from the frame bounds.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "WindowFrameSpec"))]
 pub struct WindowFrame {
     /// Frame type - either `ROWS`, `RANGE` or `GROUPS`
     pub units: WindowFrameUnits,
@@ -43,6 +53,8 @@ pub struct WindowFrame {
     pub start_bound: WindowFrameBound,
     /// Ending frame boundary
     pub end_bound: WindowFrameBound,
+    /// Rows excluded from the frame by the `EXCLUDE` clause
+    pub exclusion: WindowFrameExclusion,
     /// Flag indicating whether the frame is causal (i.e. computing the result
     /// for the current row doesn't depend on any subsequent rows).
     ///
@@ -89,9 +101,81 @@ pub struct WindowFrame {
     ///                |              |
     ///                +--------------+
     /// ```
+    #[cfg_attr(feature = "serde", serde(skip_serializing))]
     causal: bool,
 }
 
+/// Wire representation of a [`WindowFrame`], which omits the derived `causal`
+/// flag so that it is always recomputed via [`WindowFrame::new_bounds`].
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct WindowFrameSpec {
+    units: WindowFrameUnits,
+    start_bound: WindowFrameBound,
+    end_bound: WindowFrameBound,
+    #[serde(default)]
+    exclusion: WindowFrameExclusion,
+}
+
+#[cfg(feature = "serde")]
+impl From<WindowFrameSpec> for WindowFrame {
+    fn from(spec: WindowFrameSpec) -> Self {
+        Self::new_bounds(spec.units, spec.start_bound, spec.end_bound)
+            .with_exclusion(spec.exclusion)
+    }
+}
+
+/// Compares all fields except for the derived `causal` flag.
+impl PartialEq for WindowFrame {
+    fn eq(&self, other: &Self) -> bool {
+        self.units == other.units
+            && self.start_bound == other.start_bound
+            && self.end_bound == other.end_bound
+            && self.exclusion == other.exclusion
+    }
+}
+
+impl Eq for WindowFrame {}
+
+/// Compares all fields except for the derived `causal` flag, consistently
+/// with the [`PartialEq`] implementation.
+impl PartialOrd for WindowFrame {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        (
+            &self.units,
+            &self.start_bound,
+            &self.end_bound,
+            &self.exclusion,
+        )
+            .partial_cmp(&(
+                &other.units,
+                &other.start_bound,
+                &other.end_bound,
+                &other.exclusion,
+            ))
+    }
+}
+
+/// Hashes all fields except for the derived `causal` flag, consistently with
+/// the [`PartialEq`] implementation. Semantically equal frames spelled
+/// differently hash differently; use [`WindowFrame::normalize_bounds`] to
+/// deduplicate such frames.
+impl Hash for WindowFrame {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let Self {
+            units,
+            start_bound,
+            end_bound,
+            exclusion,
+            causal: _,
+        } = self;
+        units.hash(state);
+        start_bound.hash(state);
+        end_bound.hash(state);
+        exclusion.hash(state);
+    }
+}
+
 impl fmt::Display for WindowFrame {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         write!(
@@ -99,21 +183,33 @@ impl fmt::Display for WindowFrame {
             "{} BETWEEN {} AND {}",
             self.units, self.start_bound, self.end_bound
         )?;
+        if self.exclusion != WindowFrameExclusion::NoOthers {
+            write!(f, " EXCLUDE {}", self.exclusion)?;
+        }
         Ok(())
     }
 }
 
 impl fmt::Debug for WindowFrame {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "WindowFrame {{ units: {:?}, start_bound: {:?}, end_bound: {:?}, is_causal: {:?} }}",
-            self.units, self.start_bound, self.end_bound, self.causal
-        )?;
-        Ok(())
+        let mut debug = f.debug_struct("WindowFrame");
+        debug
+            .field("units", &self.units)
+            .field("start_bound", &self.start_bound)
+            .field("end_bound", &self.end_bound)
+            .field("is_causal", &self.causal);
+        if self.exclusion != WindowFrameExclusion::NoOthers {
+            debug.field("exclusion", &self.exclusion);
+        }
+        debug.finish()
     }
 }
 
+/// Converts a parsed SQL window frame into a [`WindowFrame`].
+///
+/// The sqlparser AST does not carry the `EXCLUDE` clause, so the resulting
+/// frame always uses [`WindowFrameExclusion::NoOthers`]. Use
+/// [`WindowFrame::from_str`] to parse a frame together with its exclusion.
 #[cfg(feature = "sql")]
 impl TryFrom<ast::WindowFrame> for WindowFrame {
     type Error = datafusion_common::error::DataFusionError;
@@ -142,6 +238,41 @@ impl TryFrom<ast::WindowFrame> for WindowFrame {
     }
 }
 
+/// Parses a window frame from SQL text such as
+/// `ROWS BETWEEN 1 PRECEDING AND CURRENT ROW EXCLUDE TIES`.
+///
+/// Frames with an `EXCLUDE` clause can be reasoned about, but not executed:
+/// see [`WindowFrame::with_exclusion`].
+#[cfg(feature = "sql")]
+impl FromStr for WindowFrame {
+    type Err = datafusion_common::error::DataFusionError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        use sqlparser::dialect::GenericDialect;
+        use sqlparser::keywords::Keyword;
+        use sqlparser::parser::Parser;
+        use sqlparser::tokenizer::Token;
+
+        let mut parser = Parser::new(&GenericDialect {}).try_with_sql(s)?;
+        let frame = Self::try_from(parser.parse_window_frame()?)?;
+        if !parser.parse_keyword(Keyword::EXCLUDE) {
+            return match parser.peek_token().token {
+                Token::EOF => Ok(frame),
+                token => plan_err!("Unexpected token in window frame: {token}"),
+            };
+        }
+        let mut words = vec![];
+        loop {
+            match parser.next_token().token {
+                Token::EOF => break,
+                token => words.push(token.to_string()),
+            }
+        }
+        let exclusion = words.join(" ").parse()?;
+        Ok(frame.with_exclusion(exclusion))
+    }
+}
+
 impl WindowFrame {
     /// Creates a new, default window frame (with the meaning of default
     /// depending on whether the frame contains an `ORDER BY` clause and this
@@ -160,6 +291,7 @@ impl WindowFrame {
                 },
                 start_bound: WindowFrameBound::Preceding(ScalarValue::UInt64(None)),
                 end_bound: WindowFrameBound::CurrentRow,
+                exclusion: WindowFrameExclusion::NoOthers,
                 causal: strict,
             }
         } else {
@@ -170,14 +302,50 @@ impl WindowFrame {
                 units: WindowFrameUnits::Rows,
                 start_bound: WindowFrameBound::Preceding(ScalarValue::UInt64(None)),
                 end_bound: WindowFrameBound::Following(ScalarValue::UInt64(None)),
+                exclusion: WindowFrameExclusion::NoOthers,
                 causal: false,
             }
         }
     }
 
+    /// Creates a new, default window frame like [`Self::new`], except that a
+    /// `ROWS` frame is used for non-strict orderings as well when `prefer_rows`
+    /// is set.
+    ///
+    /// Note that this changes semantics in the presence of ties: The default
+    /// `RANGE` frame ends at the last peer of the current row (i.e. rows with
+    /// the same `ORDER BY` values are all included), whereas the `ROWS` frame
+    /// ends at the current row itself. Engines opting into this trade peer
+    /// handling for the cheaper, causal `ROWS` frame.
+    pub fn new_with_rows_preference(order_by: Option<bool>, prefer_rows: bool) -> Self {
+        match order_by {
+            Some(false) if prefer_rows => Self::new(Some(true)),
+            _ => Self::new(order_by),
+        }
+    }
+
+    /// Creates a `ROWS` frame from the number of rows preceding and following
+    /// the current row. `None` denotes an unbounded side and `Some(0)` denotes
+    /// the current row, e.g. `rows(None, Some(0))` is the running frame
+    /// `ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW`.
+    pub fn rows(preceding: Option<u64>, following: Option<u64>) -> Self {
+        let start_bound = match preceding {
+            Some(0) => WindowFrameBound::CurrentRow,
+            n => WindowFrameBound::Preceding(ScalarValue::UInt64(n)),
+        };
+        let end_bound = match following {
+            Some(0) => WindowFrameBound::CurrentRow,
+            n => WindowFrameBound::Following(ScalarValue::UInt64(n)),
+        };
+        Self::new_bounds(WindowFrameUnits::Rows, start_bound, end_bound)
+    }
+
     /// Get reversed window frame. For example
     /// `3 ROWS PRECEDING AND 2 ROWS FOLLOWING` -->
     /// `2 ROWS PRECEDING AND 3 ROWS FOLLOWING`
+    ///
+    /// The `EXCLUDE` clause is carried over unchanged, as all exclusions are
+    /// symmetric around the current row.
     pub fn reverse(&self) -> Self {
         let start_bound = match &self.end_bound {
             WindowFrameBound::Preceding(value) => {
@@ -198,6 +366,59 @@ impl WindowFrame {
             WindowFrameBound::CurrentRow => WindowFrameBound::CurrentRow,
         };
         Self::new_bounds(self.units, start_bound, end_bound)
+            .with_exclusion(self.exclusion)
+    }
+
+    /// Returns whether this window frame is symmetric around the current row,
+    /// i.e. it is equal to its [reversed](Self::reverse) version once zero
+    /// offsets are treated as `CURRENT ROW`. For example, `1 PRECEDING AND
+    /// 1 FOLLOWING` and `0 PRECEDING AND CURRENT ROW` are symmetric.
+    pub fn is_symmetric(&self) -> bool {
+        let reversed = self.reverse();
+        self.start_bound.normalize_current_row()
+            == reversed.start_bound.normalize_current_row()
+            && self.end_bound.normalize_current_row()
+                == reversed.end_bound.normalize_current_row()
+    }
+
+    /// Returns an equivalent window frame in a canonical spelling, so that
+    /// semantically equal frames compare (and hash) equal. Specifically:
+    /// - Frames spanning the whole partition become `ROWS BETWEEN UNBOUNDED
+    ///   PRECEDING AND UNBOUNDED FOLLOWING`, regardless of their units.
+    /// - Unbounded offsets are represented as `UInt64` nulls.
+    /// - Bounds with zero offsets become `CURRENT ROW`.
+    /// - Untyped numeric `RANGE` offsets are spelled without redundant zeros,
+    ///   e.g. `'01'` becomes `'1'` and `'1.50'` becomes `'1.5'`. An untyped
+    ///   `'0'` offset becomes `CURRENT ROW`.
+    /// - `GROUPS` frames whose bounds are all unbounded or `CURRENT ROW`
+    ///   become `RANGE` frames, as both include entire peer groups.
+    pub fn normalize_bounds(&self) -> Self {
+        let start_bound = self.start_bound.normalize();
+        let end_bound = self.end_bound.normalize();
+        let whole_partition = start_bound
+            == WindowFrameBound::Preceding(ScalarValue::UInt64(None))
+            && end_bound == WindowFrameBound::Following(ScalarValue::UInt64(None));
+        let units = if whole_partition {
+            WindowFrameUnits::Rows
+        } else if self.units == WindowFrameUnits::Groups
+            && [&start_bound, &end_bound]
+                .iter()
+                .all(|bound| bound.is_unbounded() || bound.is_current_row())
+        {
+            WindowFrameUnits::Range
+        } else {
+            self.units
+        };
+        Self::new_bounds(units, start_bound, end_bound).with_exclusion(self.exclusion)
+    }
+
+    /// Returns whether evaluating this window frame over reversed input with
+    /// the [reversed](Self::reverse) frame is safe, which is the case for all
+    /// `ROWS` frames. For `RANGE` and `GROUPS` frames, this requires the frame
+    /// to be [symmetric](Self::is_symmetric) (e.g. unbounded on both sides),
+    /// so that the handling of peers does not depend on the direction.
+    pub fn is_reversal_safe(&self) -> bool {
+        self.units == WindowFrameUnits::Rows || self.is_symmetric()
     }
 
     /// Get whether window frame is causal
@@ -211,40 +432,106 @@ impl WindowFrame {
         start_bound: WindowFrameBound,
         end_bound: WindowFrameBound,
     ) -> Self {
-        let causal = match units {
-            WindowFrameUnits::Rows => match &end_bound {
-                WindowFrameBound::Following(value) => {
-                    if value.is_null() {
-                        // Unbounded following
-                        false
-                    } else {
-                        let zero = ScalarValue::new_zero(&value.data_type());
-                        zero.map(|zero| value.eq(&zero)).unwrap_or(false)
-                    }
-                }
-                _ => true,
-            },
-            WindowFrameUnits::Range | WindowFrameUnits::Groups => match &end_bound {
-                WindowFrameBound::Preceding(value) => {
-                    if value.is_null() {
-                        // Unbounded preceding
-                        true
-                    } else {
-                        let zero = ScalarValue::new_zero(&value.data_type());
-                        zero.map(|zero| value.gt(&zero)).unwrap_or(false)
-                    }
-                }
-                _ => false,
-            },
-        };
+        let causal = compute_causal(units, &end_bound);
         Self {
             units,
             start_bound,
             end_bound,
+            exclusion: WindowFrameExclusion::NoOthers,
             causal,
         }
     }
 
+    /// Initializes a `ROWS` window frame, see [`Self::new_bounds`].
+    pub fn new_rows(start_bound: WindowFrameBound, end_bound: WindowFrameBound) -> Self {
+        Self::new_bounds(WindowFrameUnits::Rows, start_bound, end_bound)
+    }
+
+    /// Initializes a `RANGE` window frame, see [`Self::new_bounds`].
+    pub fn new_range(start_bound: WindowFrameBound, end_bound: WindowFrameBound) -> Self {
+        Self::new_bounds(WindowFrameUnits::Range, start_bound, end_bound)
+    }
+
+    /// Initializes a `GROUPS` window frame, see [`Self::new_bounds`].
+    pub fn new_groups(
+        start_bound: WindowFrameBound,
+        end_bound: WindowFrameBound,
+    ) -> Self {
+        Self::new_bounds(WindowFrameUnits::Groups, start_bound, end_bound)
+    }
+
+    /// Sets the `EXCLUDE` clause of this window frame.
+    ///
+    /// Window operators do not yet honor frame exclusion, so this only records
+    /// the clause so that frame properties can be reasoned about. Creating a
+    /// physical window expression, or serializing the frame to protobuf,
+    /// returns a "not implemented" error for any exclusion other than
+    /// [`WindowFrameExclusion::NoOthers`].
+    pub fn with_exclusion(mut self, exclusion: WindowFrameExclusion) -> Self {
+        self.exclusion = exclusion;
+        self
+    }
+
+    /// Returns a copy of this window frame that ends at the current row, as
+    /// is the case for the default frame of an `ORDER BY` without an explicit
+    /// frame. Units, start bound and exclusion are kept as is.
+    pub fn with_end_current_row(&self) -> Self {
+        Self::new_bounds(
+            self.units,
+            self.start_bound.clone(),
+            WindowFrameBound::CurrentRow,
+        )
+        .with_exclusion(self.exclusion)
+    }
+
+    /// Returns the SQL text of this window frame like its [`Display`]
+    /// implementation, except that the `BETWEEN ... AND CURRENT ROW` form is
+    /// shortened to just the start bound (e.g. `ROWS 3 PRECEDING`) when the
+    /// frame ends at the current row. This is how users typically write
+    /// frames in queries.
+    ///
+    /// Note that `RANGE` offsets that are untyped intervals render as e.g.
+    /// `RANGE 1 DAY PRECEDING`, which is not valid SQL on its own, so the
+    /// result does not always parse back into the same frame.
+    ///
+    /// [`Display`]: fmt::Display
+    pub fn to_compact_sql(&self) -> String {
+        if self.end_bound != WindowFrameBound::CurrentRow {
+            return self.to_string();
+        }
+        let mut sql = format!("{} {}", self.units, self.start_bound);
+        if self.exclusion != WindowFrameExclusion::NoOthers {
+            sql.push_str(&format!(" EXCLUDE {}", self.exclusion));
+        }
+        sql
+    }
+
+    /// Returns the SQL text of this window frame, for embedding in queries.
+    /// See [`Self::to_compact_sql`].
+    pub fn to_sql_string(&self) -> String {
+        self.to_compact_sql()
+    }
+
+    /// Returns whether this window frame is empty for every row regardless of
+    /// the data, because its bounds select only the current row (or its group
+    /// of peers) while the `EXCLUDE` clause removes exactly those rows. For
+    /// example, `GROUPS BETWEEN CURRENT ROW AND CURRENT ROW EXCLUDE GROUP` and
+    /// `ROWS BETWEEN CURRENT ROW AND CURRENT ROW EXCLUDE CURRENT ROW` are
+    /// always empty.
+    pub fn is_provably_empty(&self) -> bool {
+        if !(self.start_bound.is_current_row() && self.end_bound.is_current_row()) {
+            return false;
+        }
+        match self.exclusion {
+            // A frame of only the current row (ROWS), or only its peers
+            // (RANGE/GROUPS), is always a subset of the current group:
+            WindowFrameExclusion::Group => true,
+            // Only for ROWS frames is the current row the only member:
+            WindowFrameExclusion::CurrentRow => self.units == WindowFrameUnits::Rows,
+            WindowFrameExclusion::Ties | WindowFrameExclusion::NoOthers => false,
+        }
+    }
+
     /// Regularizes the ORDER BY clause of the window frame.
     pub fn regularize_order_bys(&self, order_by: &mut Vec<Sort>) -> Result<()> {
         match self.units {
@@ -296,6 +583,187 @@ impl WindowFrame {
     pub fn is_ever_expanding(&self) -> bool {
         self.start_bound.is_unbounded()
     }
+
+    /// Returns whether the bounds of the window frame enclose the current row
+    /// (and its peers for RANGE/GROUPS frames); i.e. the frame does not start
+    /// after, or end before, the current row. Frames entirely in the past
+    /// (e.g. `ROWS BETWEEN 5 PRECEDING AND 2 PRECEDING`) or in the future do
+    /// not. Note that this does not take the `EXCLUDE` clause into account.
+    pub fn contains_current_row(&self) -> bool {
+        let starts_at_or_before = self.start_bound.is_current_row()
+            || matches!(self.start_bound, WindowFrameBound::Preceding(_));
+        let ends_at_or_after = self.end_bound.is_current_row()
+            || matches!(self.end_bound, WindowFrameBound::Following(_));
+        starts_at_or_before && ends_at_or_after
+    }
+
+    /// Returns whether this is a "running total" frame, i.e. it spans from
+    /// `UNBOUNDED PRECEDING` to the `CURRENT ROW`, which can be evaluated
+    /// incrementally. This holds for all frame units, e.g. for the default
+    /// frame with an ORDER BY clause. Note that this does not take the
+    /// `EXCLUDE` clause into account.
+    pub fn is_running_total(&self) -> bool {
+        matches!(&self.start_bound, WindowFrameBound::Preceding(value) if value.is_null())
+            && self.end_bound.is_current_row()
+    }
+
+    /// Returns whether the current row is a member of this frame; i.e. the
+    /// frame bounds [enclose it](Self::contains_current_row) and the `EXCLUDE`
+    /// clause does not remove it. For example, this does not hold for
+    /// `GROUPS BETWEEN 2 FOLLOWING AND 3 FOLLOWING` or for any frame with
+    /// `EXCLUDE CURRENT ROW`, while it does for frames with `EXCLUDE TIES`.
+    pub fn includes_current_row(&self) -> bool {
+        self.contains_current_row()
+            && matches!(
+                self.exclusion,
+                WindowFrameExclusion::NoOthers | WindowFrameExclusion::Ties
+            )
+    }
+
+    /// Returns whether evaluating this frame requires buffering the entire
+    /// partition, because the frame of the first row already extends to the
+    /// end of the partition; i.e. the frame ends with `UNBOUNDED FOLLOWING`.
+    /// This is the case for the default frame without an ORDER BY clause.
+    pub fn requires_full_partition(&self) -> bool {
+        matches!(&self.end_bound, WindowFrameBound::Following(value) if value.is_null())
+    }
+
+    /// Is the window frame sliding (i.e. both of its bounds are bounded, so
+    /// that rows both enter and leave the frame as the current row advances).
+    pub fn is_sliding(&self) -> bool {
+        !self.start_bound.is_unbounded() && !self.end_bound.is_unbounded()
+    }
+
+    /// Returns the number of rows a sliding `ROWS` frame spans away from the
+    /// partition boundaries (where frames get truncated). For example, this
+    /// is 4 for `ROWS BETWEEN 5 PRECEDING AND 2 PRECEDING`.
+    ///
+    /// Returns `None` for frames that are not sliding `ROWS` frames, or whose
+    /// offsets are not integers. Note that this does not take the `EXCLUDE`
+    /// clause into account.
+    pub fn fixed_rows_len(&self) -> Option<usize> {
+        if self.units != WindowFrameUnits::Rows || !self.is_sliding() {
+            return None;
+        }
+        let len = rows_position(&self.end_bound)? - rows_position(&self.start_bound)? + 1;
+        Some(usize::try_from(len.max(0)).unwrap_or(usize::MAX))
+    }
+
+    /// Returns the number of rows in a sliding `ROWS` frame after applying
+    /// its `EXCLUDE` clause, ignoring partition boundaries. This is
+    /// [`Self::fixed_rows_len`] minus the excluded rows; e.g. 4 for `ROWS
+    /// BETWEEN 2 PRECEDING AND 1 FOLLOWING`, and 2 for `ROWS BETWEEN 2
+    /// PRECEDING AND CURRENT ROW EXCLUDE CURRENT ROW`.
+    ///
+    /// Returns `None` under the same conditions as [`Self::fixed_rows_len`],
+    /// and also for `EXCLUDE GROUP` and `EXCLUDE TIES`, as the number of peers
+    /// these remove depends on the data.
+    pub fn frame_size(&self) -> Option<usize> {
+        let excluded = match self.exclusion {
+            WindowFrameExclusion::NoOthers => 0,
+            WindowFrameExclusion::CurrentRow => usize::from(self.contains_current_row()),
+            WindowFrameExclusion::Group | WindowFrameExclusion::Ties => return None,
+        };
+        Some(self.fixed_rows_len()? - excluded)
+    }
+
+    /// Returns the range of row indices this frame spans for the row at
+    /// `row_idx` in a partition with `partition_len` rows, clamped to the
+    /// partition boundaries. The range is empty if the frame contains no rows
+    /// (e.g. when it is [provably empty](Self::is_provably_empty)).
+    ///
+    /// Returns `None` if this is not a `ROWS` frame, its offsets are not
+    /// integers, its `EXCLUDE` clause depends on peers or splits the frame,
+    /// or `row_idx` is out of bounds.
+    pub fn rows_frame_at(
+        &self,
+        row_idx: usize,
+        partition_len: usize,
+    ) -> Option<Range<usize>> {
+        if self.units != WindowFrameUnits::Rows || row_idx >= partition_len {
+            return None;
+        }
+        if self.is_provably_empty() {
+            return Some(row_idx..row_idx);
+        }
+        if self.exclusion != WindowFrameExclusion::NoOthers {
+            return None;
+        }
+        // Computes the row index a bound points at for a row at `row_idx`:
+        let position = |bound: &WindowFrameBound, row_idx: usize| -> Option<usize> {
+            Some(match bound {
+                WindowFrameBound::Preceding(value) if value.is_null() => 0,
+                WindowFrameBound::Preceding(value) => {
+                    row_idx.saturating_sub(rows_offset(value)?)
+                }
+                WindowFrameBound::CurrentRow => row_idx,
+                WindowFrameBound::Following(value) if value.is_null() => partition_len,
+                WindowFrameBound::Following(value) => {
+                    row_idx.saturating_add(rows_offset(value)?)
+                }
+            })
+        };
+        let start = position(&self.start_bound, row_idx)?.min(partition_len);
+        // Shift by one row to get an exclusive end:
+        let end = position(&self.end_bound, row_idx + 1)?
+            .min(partition_len)
+            .max(start);
+        Some(start..end)
+    }
+}
+
+/// Computes whether a window frame with the given units and end bound is
+/// causal, i.e. whether it never reads rows after the current row (or its
+/// peers for `RANGE` / `GROUPS` frames). This is what
+/// [`WindowFrame::new_bounds`] uses to derive [`WindowFrame::is_causal`], and
+/// can be used to recompute it for frames reconstructed field by field.
+pub fn compute_causal(units: WindowFrameUnits, end_bound: &WindowFrameBound) -> bool {
+    match units {
+        WindowFrameUnits::Rows => match end_bound {
+            WindowFrameBound::Following(value) => {
+                if value.is_null() {
+                    // Unbounded following
+                    false
+                } else {
+                    let zero = ScalarValue::new_zero(&value.data_type());
+                    zero.map(|zero| value.eq(&zero)).unwrap_or(false)
+                }
+            }
+            _ => true,
+        },
+        WindowFrameUnits::Range | WindowFrameUnits::Groups => match end_bound {
+            WindowFrameBound::Preceding(value) => {
+                if value.is_null() {
+                    // Unbounded preceding
+                    true
+                } else {
+                    let zero = ScalarValue::new_zero(&value.data_type());
+                    zero.map(|zero| value.gt(&zero)).unwrap_or(false)
+                }
+            }
+            _ => false,
+        },
+    }
+}
+
+/// Converts the given (non-null) `ROWS` frame offset to a `usize`, if possible.
+fn rows_offset(value: &ScalarValue) -> Option<usize> {
+    match value.cast_to(&DataType::UInt64).ok()? {
+        ScalarValue::UInt64(Some(offset)) => {
+            Some(usize::try_from(offset).unwrap_or(usize::MAX))
+        }
+        _ => None,
+    }
+}
+
+/// Computes the position of the given bounded `ROWS` frame bound relative to
+/// the current row, if its offset is an integer.
+fn rows_position(bound: &WindowFrameBound) -> Option<i128> {
+    Some(match bound {
+        WindowFrameBound::Preceding(value) => -(rows_offset(value)? as i128),
+        WindowFrameBound::CurrentRow => 0,
+        WindowFrameBound::Following(value) => rows_offset(value)? as i128,
+    })
 }
 
 /// There are five ways to describe starting and ending frame boundaries:
@@ -306,6 +774,7 @@ impl WindowFrame {
 /// 4. `<expr>` FOLLOWING
 /// 5. UNBOUNDED FOLLOWING
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum WindowFrameBound {
     /// 1. UNBOUNDED PRECEDING
     ///    The frame boundary is the first row in the partition.
@@ -313,7 +782,7 @@ pub enum WindowFrameBound {
     /// 2. `<expr>` PRECEDING
     ///    `<expr>` must be a non-negative constant numeric expression. The boundary is a row that
     ///    is `<expr>` "units" prior to the current row.
-    Preceding(ScalarValue),
+    Preceding(#[cfg_attr(feature = "serde", serde(with = "serde_offset"))] ScalarValue),
     /// 3. The current row.
     ///
     /// For RANGE and GROUPS frame types, peers of the current row are also
@@ -326,7 +795,57 @@ pub enum WindowFrameBound {
     ///
     /// 5. UNBOUNDED FOLLOWING
     ///    The frame boundary is the last row in the partition.
-    Following(ScalarValue),
+    Following(#[cfg_attr(feature = "serde", serde(with = "serde_offset"))] ScalarValue),
+}
+
+/// (De)serialization of frame offsets. Since [`ScalarValue`] does not implement
+/// the `serde` traits, offsets are represented by their data type and value
+/// (if not null) as strings. Values are converted with Arrow's `Utf8` cast,
+/// which unlike [`ScalarValue`]'s `Display` can be cast back to every offset
+/// type (e.g. decimals and intervals).
+#[cfg(feature = "serde")]
+mod serde_offset {
+    use std::str::FromStr;
+
+    use arrow::datatypes::DataType;
+    use datafusion_common::ScalarValue;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use serde::{de, ser};
+
+    #[derive(Serialize, Deserialize)]
+    struct Offset {
+        data_type: String,
+        value: Option<String>,
+    }
+
+    pub fn serialize<S: Serializer>(
+        value: &ScalarValue,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let value_str = match value.cast_to(&DataType::Utf8) {
+            Ok(ScalarValue::Utf8(value_str)) => value_str,
+            Ok(other) => unreachable!("cast to Utf8 returned {other:?}"),
+            Err(e) => return Err(ser::Error::custom(e)),
+        };
+        let offset = Offset {
+            data_type: value.data_type().to_string(),
+            value: value_str,
+        };
+        offset.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<ScalarValue, D::Error> {
+        let offset = Offset::deserialize(deserializer)?;
+        let data_type =
+            DataType::from_str(&offset.data_type).map_err(de::Error::custom)?;
+        match offset.value {
+            Some(value) => ScalarValue::try_from_string(value, &data_type),
+            None => ScalarValue::try_from(&data_type),
+        }
+        .map_err(de::Error::custom)
+    }
 }
 
 impl WindowFrameBound {
@@ -337,6 +856,210 @@ impl WindowFrameBound {
             WindowFrameBound::Following(elem) => elem.is_null(),
         }
     }
+
+    /// Returns whether this bound points at the current row; i.e. it is either
+    /// `CURRENT ROW` or has a zero offset.
+    pub fn is_current_row(&self) -> bool {
+        match self {
+            WindowFrameBound::CurrentRow => true,
+            WindowFrameBound::Preceding(value) | WindowFrameBound::Following(value) => {
+                ScalarValue::new_zero(&value.data_type())
+                    .is_ok_and(|zero| !value.is_null() && value.eq(&zero))
+            }
+        }
+    }
+
+    /// Compares the positions of this bound and `other` along the
+    /// past-to-future axis, so that e.g. `2 PRECEDING` is less than
+    /// `1 PRECEDING`, which in turn is less than `CURRENT ROW`. Bounds with a
+    /// zero offset are positioned at the current row.
+    ///
+    /// Unlike the derived [`PartialOrd`] implementation, which compares the
+    /// offsets of `PRECEDING` bounds in their natural (rather than positional)
+    /// order, this method returns `None` only when the offsets of two bounded
+    /// `PRECEDING` or `FOLLOWING` bounds are not comparable; e.g. when they
+    /// have different types, or are unparsed `RANGE` offsets stored as strings.
+    pub fn cmp_position(&self, other: &Self) -> Option<Ordering> {
+        // Ranks the bound kinds from the start to the end of the partition:
+        let rank = |bound: &Self| match bound {
+            _ if bound.is_current_row() => 2,
+            WindowFrameBound::Preceding(value) if value.is_null() => 0,
+            WindowFrameBound::Preceding(_) => 1,
+            WindowFrameBound::CurrentRow => 2,
+            WindowFrameBound::Following(value) if value.is_null() => 4,
+            WindowFrameBound::Following(_) => 3,
+        };
+        match rank(self).cmp(&rank(other)) {
+            Ordering::Equal => {}
+            ordering => return Some(ordering),
+        }
+        match (self, other) {
+            (WindowFrameBound::Preceding(lhs), WindowFrameBound::Preceding(rhs))
+            | (WindowFrameBound::Following(rhs), WindowFrameBound::Following(lhs))
+                if !lhs.is_null() && !rhs.is_null() && lhs != rhs =>
+            {
+                // String offsets would compare lexicographically:
+                let is_string = |value: &ScalarValue| {
+                    matches!(
+                        value.data_type(),
+                        DataType::Utf8 | DataType::LargeUtf8 | DataType::Utf8View
+                    )
+                };
+                if is_string(lhs) || is_string(rhs) {
+                    return None;
+                }
+                // A larger PRECEDING offset points further into the past,
+                // hence the operands are swapped for FOLLOWING bounds above:
+                rhs.partial_cmp(lhs)
+            }
+            _ => Some(Ordering::Equal),
+        }
+    }
+
+    /// Shifts this bound by `delta` rows (or groups) towards the end of the
+    /// partition, or towards its start if `delta` is negative. For example,
+    /// shifting `1 PRECEDING` by `-2` gives `3 PRECEDING`, and by `3` gives
+    /// `2 FOLLOWING`. A bound shifted onto the current row keeps its kind,
+    /// e.g. shifting `2 PRECEDING` by `2` gives `0 PRECEDING`. Shifted
+    /// offsets keep the data type of the original offset.
+    ///
+    /// `CURRENT ROW` and unbounded bounds are returned as is. Returns an error
+    /// if the offset is not an integer (e.g. an unparsed `RANGE` offset stored
+    /// as a string), or if the shifted offset overflows.
+    pub fn shift_by(&self, delta: i64) -> Result<WindowFrameBound> {
+        let value = match self {
+            WindowFrameBound::Preceding(value) | WindowFrameBound::Following(value)
+                if !value.is_null() =>
+            {
+                value
+            }
+            _ => return Ok(self.clone()),
+        };
+        // Other types may be cast to integers, but would lose information
+        // (e.g. fractional offsets, or untyped offsets that are intervals):
+        let position = value
+            .data_type()
+            .is_integer()
+            .then(|| rows_position(self))
+            .flatten();
+        let Some(position) = position else {
+            return plan_err!(
+                "Cannot shift window frame bound {self}: offset is not an integer"
+            );
+        };
+        let position = position + i128::from(delta);
+        let offset = u64::try_from(position.unsigned_abs())
+            .ok()
+            .and_then(|offset| {
+                ScalarValue::UInt64(Some(offset))
+                    .cast_to(&value.data_type())
+                    .ok()
+            });
+        let Some(offset) = offset else {
+            return plan_err!(
+                "Cannot shift window frame bound {self} by {delta}: offset overflows"
+            );
+        };
+        Ok(match position.cmp(&0) {
+            Ordering::Less => WindowFrameBound::Preceding(offset),
+            Ordering::Greater => WindowFrameBound::Following(offset),
+            Ordering::Equal => match self {
+                WindowFrameBound::Preceding(_) => WindowFrameBound::Preceding(offset),
+                _ => WindowFrameBound::Following(offset),
+            },
+        })
+    }
+
+    /// Returns the offset of this `RANGE` bound as a [`ScalarValue`] that can
+    /// be compared against an `ORDER BY` expression of type `order_by_type`.
+    /// Untyped (`Utf8`) offsets are parsed as numbers for numeric types, and
+    /// as `Interval(MonthDayNano)` values (e.g. `1 DAY`) for date/time types.
+    /// Unbounded bounds yield a null value, and `CURRENT ROW` a zero value.
+    ///
+    /// Executors can call this once per frame and cache the result instead of
+    /// parsing offsets for every partition.
+    pub fn as_range_scalar(&self, order_by_type: &DataType) -> Result<ScalarValue> {
+        let target_type = match order_by_type {
+            DataType::Dictionary(_, value_type) => {
+                return self.as_range_scalar(value_type);
+            }
+            DataType::Date32
+            | DataType::Date64
+            | DataType::Time32(_)
+            | DataType::Time64(_)
+            | DataType::Timestamp(_, _) => {
+                DataType::Interval(arrow::datatypes::IntervalUnit::MonthDayNano)
+            }
+            _ => order_by_type.clone(),
+        };
+        match self {
+            WindowFrameBound::CurrentRow => ScalarValue::new_zero(&target_type),
+            WindowFrameBound::Preceding(value) | WindowFrameBound::Following(value)
+                if value.is_null() =>
+            {
+                ScalarValue::try_from(&target_type)
+            }
+            WindowFrameBound::Preceding(value) | WindowFrameBound::Following(value) => {
+                match value {
+                    ScalarValue::Utf8(Some(offset)) if target_type != DataType::Utf8 => {
+                        ScalarValue::try_from_string(offset.clone(), &target_type)
+                    }
+                    _ => value.cast_to(&target_type),
+                }
+            }
+        }
+    }
+
+    /// Returns this bound in a canonical spelling, see
+    /// [`WindowFrame::normalize_bounds`].
+    fn normalize(&self) -> Self {
+        let normalized = match self {
+            WindowFrameBound::Preceding(value) if value.is_null() => {
+                WindowFrameBound::Preceding(ScalarValue::UInt64(None))
+            }
+            WindowFrameBound::Following(value) if value.is_null() => {
+                WindowFrameBound::Following(ScalarValue::UInt64(None))
+            }
+            WindowFrameBound::Preceding(ScalarValue::Utf8(Some(value))) => {
+                match normalize_numeric_offset(value) {
+                    Some(value) => {
+                        WindowFrameBound::Preceding(ScalarValue::Utf8(Some(value)))
+                    }
+                    None => self.clone(),
+                }
+            }
+            WindowFrameBound::Following(ScalarValue::Utf8(Some(value))) => {
+                match normalize_numeric_offset(value) {
+                    Some(value) => {
+                        WindowFrameBound::Following(ScalarValue::Utf8(Some(value)))
+                    }
+                    None => self.clone(),
+                }
+            }
+            _ => self.clone(),
+        };
+        match &normalized {
+            WindowFrameBound::Preceding(ScalarValue::Utf8(Some(value)))
+            | WindowFrameBound::Following(ScalarValue::Utf8(Some(value)))
+                if value == "0" =>
+            {
+                WindowFrameBound::CurrentRow
+            }
+            _ => normalized.normalize_current_row(),
+        }
+    }
+
+    /// Returns `CURRENT ROW` if this bound [points at the current row], or a
+    /// copy of this bound otherwise.
+    ///
+    /// [points at the current row]: Self::is_current_row
+    fn normalize_current_row(&self) -> Self {
+        if self.is_current_row() {
+            WindowFrameBound::CurrentRow
+        } else {
+            self.clone()
+        }
+    }
 }
 
 impl WindowFrameBound {
@@ -345,31 +1068,141 @@ impl WindowFrameBound {
         value: ast::WindowFrameBound,
         units: &ast::WindowFrameUnits,
     ) -> Result<Self> {
-        Ok(match value {
-            ast::WindowFrameBound::Preceding(Some(v)) => {
-                Self::Preceding(convert_frame_bound_to_scalar_value(*v, units)?)
-            }
+        Self::try_parse_with_type(value, units, None)
+    }
+
+    /// Parses a frame bound like [`WindowFrame::try_from`] does, except that
+    /// numeric RANGE offsets are converted to `data_type` (typically the type
+    /// of the ORDER BY column) when given, instead of being stored as `Utf8`
+    /// strings. Likewise, interval offsets over date/time types are converted
+    /// to `Interval(MonthDayNano)` values. Typed offsets let
+    /// [`WindowFrame::new_bounds`] derive causality of RANGE frames
+    /// correctly. Since typed offsets have a sign, negative offsets are
+    /// reconciled with the opposite direction; e.g. `-10 FOLLOWING` becomes
+    /// `10 PRECEDING`.
+    #[cfg(feature = "sql")]
+    pub fn try_parse_with_type(
+        value: ast::WindowFrameBound,
+        units: &ast::WindowFrameUnits,
+        data_type: Option<&DataType>,
+    ) -> Result<Self> {
+        let bound = match value {
+            ast::WindowFrameBound::Preceding(Some(v)) => Self::Preceding(
+                convert_frame_bound_to_scalar_value(*v, units, data_type)?,
+            ),
             ast::WindowFrameBound::Preceding(None) => {
                 Self::Preceding(ScalarValue::UInt64(None))
             }
-            ast::WindowFrameBound::Following(Some(v)) => {
-                Self::Following(convert_frame_bound_to_scalar_value(*v, units)?)
-            }
+            ast::WindowFrameBound::Following(Some(v)) => Self::Following(
+                convert_frame_bound_to_scalar_value(*v, units, data_type)?,
+            ),
             ast::WindowFrameBound::Following(None) => {
                 Self::Following(ScalarValue::UInt64(None))
             }
             ast::WindowFrameBound::CurrentRow => Self::CurrentRow,
+        };
+        bound.flip_negative_offset()
+    }
+
+    /// Converts a bound with a negative offset into the equivalent bound in
+    /// the opposite direction with a positive offset. Bounds with unsigned or
+    /// non-numeric offsets are returned as is.
+    #[cfg(feature = "sql")]
+    fn flip_negative_offset(self) -> Result<Self> {
+        let is_negative = |value: &ScalarValue| {
+            ScalarValue::new_zero(&value.data_type())
+                .is_ok_and(|zero| !value.is_null() && value.lt(&zero))
+        };
+        Ok(match self {
+            Self::Preceding(value) if is_negative(&value) => {
+                Self::Following(value.arithmetic_negate()?)
+            }
+            Self::Following(value) if is_negative(&value) => {
+                Self::Preceding(value.arithmetic_negate()?)
+            }
+            bound => bound,
         })
     }
 }
 
+/// Returns the (possibly signed) number in `expr` if it is a numeric literal.
+#[cfg(feature = "sql")]
+fn signed_number(expr: &ast::Expr) -> Option<String> {
+    match expr {
+        ast::Expr::Value(ValueWithSpan {
+            value: ast::Value::Number(value, false),
+            span: _,
+        }) => Some(value.clone()),
+        ast::Expr::UnaryOp {
+            op: ast::UnaryOperator::Minus,
+            expr,
+        } => signed_number(expr).map(|value| format!("-{value}")),
+        ast::Expr::UnaryOp {
+            op: ast::UnaryOperator::Plus,
+            expr,
+        } => signed_number(expr),
+        _ => None,
+    }
+}
+
+/// Removes redundant zeros from an untyped numeric offset, e.g. `01.50` becomes
+/// `1.5`. Fractional parts are never dropped entirely (`1.00` becomes `1.0`),
+/// as they determine which `ORDER BY` types the offset can be coerced to.
+/// Returns `None` if `value` is not a plain non-negative decimal number.
+fn normalize_numeric_offset(value: &str) -> Option<String> {
+    let (integer, fraction) = match value.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (value, None),
+    };
+    let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if integer.is_empty() || !is_digits(integer) || !fraction.is_none_or(is_digits) {
+        return None;
+    }
+    let integer = match integer.trim_start_matches('0') {
+        "" => "0",
+        integer => integer,
+    };
+    Some(
+        match fraction.map(|fraction| fraction.trim_end_matches('0')) {
+            None => integer.to_string(),
+            Some("") => format!("{integer}.0"),
+            Some(fraction) => format!("{integer}.{fraction}"),
+        },
+    )
+}
+
+/// Converts the offset of a frame bound into a [`ScalarValue`]. Errors carry a
+/// [`Diagnostic`] pointing at the offending offset in the original SQL.
+///
+/// [`Diagnostic`]: datafusion_common::Diagnostic
 #[cfg(feature = "sql")]
 fn convert_frame_bound_to_scalar_value(
     v: ast::Expr,
     units: &ast::WindowFrameUnits,
+    data_type: Option<&DataType>,
+) -> Result<ScalarValue> {
+    use datafusion_common::{Diagnostic, Span};
+    use sqlparser::ast::Spanned;
+
+    let span = Span::try_from_sqlparser_span(v.span());
+    frame_offset_to_scalar_value(v, units, data_type)
+        .map_err(|e| e.with_diagnostic_fn(|e| Diagnostic::new_error(e.message(), span)))
+}
+
+#[cfg(feature = "sql")]
+fn frame_offset_to_scalar_value(
+    v: ast::Expr,
+    units: &ast::WindowFrameUnits,
+    data_type: Option<&DataType>,
 ) -> Result<ScalarValue> {
-    use arrow::datatypes::DataType;
     use datafusion_common::exec_err;
+    if let (ast::WindowFrameUnits::Range, Some(data_type)) = (units, data_type)
+        && let Some(value) = signed_number(&v)
+    {
+        // If we know the type of the ORDER BY clause, we can type numeric
+        // RANGE offsets right away:
+        return ScalarValue::try_from_string(value, data_type);
+    }
     match units {
         // For ROWS and GROUPS we are sure that the ScalarValue must be a non-negative integer ...
         ast::WindowFrameUnits::Rows | ast::WindowFrameUnits::Groups => match v {
@@ -420,14 +1253,41 @@ fn convert_frame_bound_to_scalar_value(
                         return exec_err!("INTERVAL expression cannot be {e:?}");
                     }
                 };
-                if let Some(leading_field) = leading_field {
+                let result = if let Some(leading_field) = leading_field {
                     format!("{result} {leading_field}")
                 } else {
                     result
+                };
+                let interval_type =
+                    DataType::Interval(arrow::datatypes::IntervalUnit::MonthDayNano);
+                // Intervals over date/time ORDER BY clauses can be typed right
+                // away, see the numeric case above:
+                if matches!(
+                    data_type,
+                    Some(
+                        DataType::Date32
+                            | DataType::Date64
+                            | DataType::Time32(_)
+                            | DataType::Time64(_)
+                            | DataType::Timestamp(_, _)
+                    )
+                ) {
+                    return ScalarValue::try_from_string(result, &interval_type);
+                }
+                // Otherwise, make sure the offset can be coerced to the ORDER
+                // BY type later on instead of failing during execution:
+                if result.parse::<f64>().is_err()
+                    && ScalarValue::try_from_string(result.clone(), &interval_type)
+                        .is_err()
+                {
+                    return plan_err!(
+                        "Invalid window frame: RANGE offset '{result}' is neither a numeric value nor an interval"
+                    );
                 }
+                result
             }
             _ => plan_err!(
-                "Invalid window frame: frame offsets for RANGE must be either a numeric value, a string value or an interval"
+                "Invalid window frame: frame offsets for RANGE must be either a numeric value, a string value or an interval, got {v}"
             )?,
         }))),
     }
@@ -458,6 +1318,7 @@ impl fmt::Display for WindowFrameBound {
 /// There are three frame types: ROWS, GROUPS, and RANGE. The frame type determines how the
 /// starting and ending boundaries of the frame are measured.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum WindowFrameUnits {
     /// The ROWS frame type means that the starting and ending boundaries for the frame are
     /// determined by counting individual rows relative to the current row.
@@ -474,6 +1335,20 @@ pub enum WindowFrameUnits {
     Groups,
 }
 
+impl WindowFrameUnits {
+    /// Returns whether frame bounds in these units count rows relative to the
+    /// current row, i.e. whether these are `ROWS` or `GROUPS` units.
+    pub fn is_row_based(&self) -> bool {
+        matches!(self, WindowFrameUnits::Rows | WindowFrameUnits::Groups)
+    }
+
+    /// Returns whether frame bounds in these units compare values of the
+    /// `ORDER BY` expression, i.e. whether these are `RANGE` units.
+    pub fn is_value_based(&self) -> bool {
+        matches!(self, WindowFrameUnits::Range)
+    }
+}
+
 impl fmt::Display for WindowFrameUnits {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         f.write_str(match self {
@@ -484,13 +1359,70 @@ impl fmt::Display for WindowFrameUnits {
     }
 }
 
-#[cfg(feature = "sql")]
-impl From<ast::WindowFrameUnits> for WindowFrameUnits {
-    fn from(value: ast::WindowFrameUnits) -> Self {
-        match value {
-            ast::WindowFrameUnits::Range => Self::Range,
-            ast::WindowFrameUnits::Groups => Self::Groups,
-            ast::WindowFrameUnits::Rows => Self::Rows,
+impl TryFrom<&str> for WindowFrameUnits {
+    type Error = datafusion_common::error::DataFusionError;
+
+    /// Parses frame units from their SQL keyword, ignoring case.
+    fn try_from(value: &str) -> Result<Self> {
+        match value.to_uppercase().as_str() {
+            "ROWS" => Ok(WindowFrameUnits::Rows),
+            "RANGE" => Ok(WindowFrameUnits::Range),
+            "GROUPS" => Ok(WindowFrameUnits::Groups),
+            _ => plan_err!("Invalid window frame units: {value}"),
+        }
+    }
+}
+
+/// The `EXCLUDE` clause of a window frame removes rows around the current row
+/// from the frame, even if they are within the frame bounds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WindowFrameExclusion {
+    /// No rows are excluded. This is the default.
+    #[default]
+    NoOthers,
+    /// The current row is excluded from the frame.
+    CurrentRow,
+    /// The current row and all its peers are excluded from the frame.
+    Group,
+    /// All peers of the current row are excluded from the frame, but the
+    /// current row itself is not.
+    Ties,
+}
+
+impl fmt::Display for WindowFrameExclusion {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(match self {
+            WindowFrameExclusion::NoOthers => "NO OTHERS",
+            WindowFrameExclusion::CurrentRow => "CURRENT ROW",
+            WindowFrameExclusion::Group => "GROUP",
+            WindowFrameExclusion::Ties => "TIES",
+        })
+    }
+}
+
+impl FromStr for WindowFrameExclusion {
+    type Err = datafusion_common::error::DataFusionError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let words = s.split_whitespace().collect::<Vec<_>>().join(" ");
+        match words.to_uppercase().as_str() {
+            "NO OTHERS" => Ok(WindowFrameExclusion::NoOthers),
+            "CURRENT ROW" => Ok(WindowFrameExclusion::CurrentRow),
+            "GROUP" => Ok(WindowFrameExclusion::Group),
+            "TIES" => Ok(WindowFrameExclusion::Ties),
+            _ => plan_err!("Invalid window frame exclusion: EXCLUDE {s}"),
+        }
+    }
+}
+
+#[cfg(feature = "sql")]
+impl From<ast::WindowFrameUnits> for WindowFrameUnits {
+    fn from(value: ast::WindowFrameUnits) -> Self {
+        match value {
+            ast::WindowFrameUnits::Range => Self::Range,
+            ast::WindowFrameUnits::Groups => Self::Groups,
+            ast::WindowFrameUnits::Rows => Self::Rows,
         }
     }
 }
@@ -579,6 +1511,158 @@ mod tests {
         };
     }
 
+    #[test]
+    fn test_window_frame_new_with_rows_preference() {
+        // Without a preference, non-strict orderings get a RANGE frame:
+        let frame = WindowFrame::new_with_rows_preference(Some(false), false);
+        assert_eq!(frame, WindowFrame::new(Some(false)));
+        assert_eq!(frame.units, WindowFrameUnits::Range);
+        assert!(!frame.is_causal());
+
+        // With a preference, non-strict orderings get a ROWS frame:
+        let frame = WindowFrame::new_with_rows_preference(Some(false), true);
+        assert_eq!(frame.units, WindowFrameUnits::Rows);
+        assert_eq!(
+            frame.start_bound,
+            WindowFrameBound::Preceding(ScalarValue::UInt64(None))
+        );
+        assert_eq!(frame.end_bound, WindowFrameBound::CurrentRow);
+        assert!(frame.is_causal());
+
+        // Strict orderings and frames without ordering are not affected:
+        for prefer_rows in [false, true] {
+            for order_by in [None, Some(true)] {
+                assert_eq!(
+                    WindowFrame::new_with_rows_preference(order_by, prefer_rows),
+                    WindowFrame::new(order_by)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_window_frame_debug() {
+        let frame = WindowFrame::rows(Some(1), Some(0));
+        assert_eq!(
+            format!("{frame:?}"),
+            "WindowFrame { units: Rows, start_bound: Preceding(UInt64(1)), end_bound: CurrentRow, is_causal: true }"
+        );
+        let frame = frame.with_exclusion(WindowFrameExclusion::Ties);
+        assert_eq!(
+            format!("{frame:?}"),
+            "WindowFrame { units: Rows, start_bound: Preceding(UInt64(1)), end_bound: CurrentRow, is_causal: true, exclusion: Ties }"
+        );
+    }
+
+    #[test]
+    fn test_window_frame_to_compact_sql() -> Result<()> {
+        let cases = [
+            (WindowFrame::rows(Some(3), Some(0)), "ROWS 3 PRECEDING"),
+            (WindowFrame::new(Some(false)), "RANGE UNBOUNDED PRECEDING"),
+            (WindowFrame::rows(Some(0), Some(0)), "ROWS CURRENT ROW"),
+            (
+                WindowFrame::new_bounds(
+                    WindowFrameUnits::Groups,
+                    WindowFrameBound::Preceding(ScalarValue::UInt64(Some(2))),
+                    WindowFrameBound::CurrentRow,
+                )
+                .with_exclusion(WindowFrameExclusion::Ties),
+                "GROUPS 2 PRECEDING EXCLUDE TIES",
+            ),
+            // Frames not ending at the current row keep the explicit form:
+            (
+                WindowFrame::rows(Some(3), Some(2)),
+                "ROWS BETWEEN 3 PRECEDING AND 2 FOLLOWING",
+            ),
+            (
+                WindowFrame::new(None),
+                "ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING",
+            ),
+            (
+                WindowFrame::rows(Some(1), Some(1))
+                    .with_exclusion(WindowFrameExclusion::CurrentRow),
+                "ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING EXCLUDE CURRENT ROW",
+            ),
+        ];
+        for (frame, expected) in cases {
+            let sql = frame.to_compact_sql();
+            assert_eq!(sql, expected);
+            assert_eq!(frame.to_sql_string(), expected);
+            assert_eq!(sql.parse::<WindowFrame>()?, frame, "{sql}");
+        }
+        // Display keeps the explicit form:
+        assert_eq!(
+            WindowFrame::rows(Some(3), Some(0)).to_string(),
+            "ROWS BETWEEN 3 PRECEDING AND CURRENT ROW"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_window_frame_with_end_current_row() {
+        let whole = WindowFrame::new(None);
+        assert!(!whole.is_causal());
+        let running = whole.with_end_current_row();
+        assert_eq!(running, WindowFrame::rows(None, Some(0)));
+        assert!(running.is_causal());
+
+        // Units, start bound and exclusion are kept:
+        let frame = WindowFrame::new_bounds(
+            WindowFrameUnits::Range,
+            WindowFrameBound::Preceding(ScalarValue::UInt64(Some(2))),
+            WindowFrameBound::Following(ScalarValue::UInt64(None)),
+        )
+        .with_exclusion(WindowFrameExclusion::Ties)
+        .with_end_current_row();
+        assert_eq!(
+            frame.to_string(),
+            "RANGE BETWEEN 2 PRECEDING AND CURRENT ROW EXCLUDE TIES"
+        );
+        assert!(!frame.is_causal());
+    }
+
+    #[test]
+    fn test_window_frame_rows() {
+        // Running total:
+        let frame = WindowFrame::rows(None, Some(0));
+        assert_eq!(frame, WindowFrame::new(Some(true)));
+        assert!(frame.is_causal());
+        assert!(frame.is_ever_expanding());
+        assert_eq!(
+            frame.to_string(),
+            "ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW"
+        );
+
+        // Sliding frame:
+        let frame = WindowFrame::rows(Some(3), Some(2));
+        assert_eq!(
+            frame.start_bound,
+            WindowFrameBound::Preceding(ScalarValue::UInt64(Some(3)))
+        );
+        assert_eq!(
+            frame.end_bound,
+            WindowFrameBound::Following(ScalarValue::UInt64(Some(2)))
+        );
+        assert!(!frame.is_causal());
+        assert!(frame.is_sliding());
+        assert_eq!(frame.fixed_rows_len(), Some(6));
+
+        // Whole partition:
+        let frame = WindowFrame::rows(None, None);
+        assert_eq!(
+            frame.to_string(),
+            "ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING"
+        );
+        assert!(!frame.is_causal());
+        assert!(!frame.is_sliding());
+
+        // Only the current row:
+        let frame = WindowFrame::rows(Some(0), Some(0));
+        assert_eq!(frame.start_bound, WindowFrameBound::CurrentRow);
+        assert_eq!(frame.end_bound, WindowFrameBound::CurrentRow);
+        assert_eq!(frame.fixed_rows_len(), Some(1));
+    }
+
     #[test]
     fn test_window_frame_bound_creation() -> Result<()> {
         //  Unbounded
@@ -624,6 +1708,1173 @@ mod tests {
             ScalarValue::Utf8(Some("1 DAY".to_string()))
         );
 
+        // Invalid RANGE offsets
+        let interval = |value: &str| {
+            Some(Box::new(ast::Expr::Interval(ast::Interval {
+                value: Box::new(ast::Expr::Value(
+                    ast::Value::SingleQuotedString(value.to_string()).into(),
+                )),
+                leading_field: None,
+                fractional_seconds_precision: None,
+                last_field: None,
+                leading_precision: None,
+            })))
+        };
+        test_bound!(
+            Range,
+            interval("1.5"),
+            ScalarValue::Utf8(Some("1.5".to_string()))
+        );
+        test_bound_err!(
+            Range,
+            interval("abc"),
+            "Error during planning: Invalid window frame: RANGE offset 'abc' is neither a numeric value nor an interval"
+        );
+        test_bound_err!(
+            Range,
+            Some(Box::new(ast::Expr::Identifier(ast::Ident::new("abc")))),
+            "Error during planning: Invalid window frame: frame offsets for RANGE must be either a numeric value, a string value or an interval, got abc"
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_window_frame_serde_roundtrip() -> Result<()> {
+        let frames = [
+            WindowFrame::new(None),
+            WindowFrame::new(Some(true)),
+            WindowFrame::new(Some(false)),
+            WindowFrame::new_bounds(
+                WindowFrameUnits::Rows,
+                WindowFrameBound::Preceding(ScalarValue::UInt64(Some(3))),
+                WindowFrameBound::Following(ScalarValue::UInt64(Some(2))),
+            ),
+            WindowFrame::new_bounds(
+                WindowFrameUnits::Range,
+                WindowFrameBound::Preceding(ScalarValue::Utf8(Some("1 DAY".into()))),
+                WindowFrameBound::Following(ScalarValue::UInt64(None)),
+            ),
+            WindowFrame::new_bounds(
+                WindowFrameUnits::Range,
+                WindowFrameBound::Preceding(ScalarValue::Int64(Some(10))),
+                WindowFrameBound::Preceding(ScalarValue::Int64(Some(5))),
+            ),
+            WindowFrame::new_bounds(
+                WindowFrameUnits::Groups,
+                WindowFrameBound::CurrentRow,
+                WindowFrameBound::Following(ScalarValue::UInt64(None)),
+            ),
+            WindowFrame::new_bounds(
+                WindowFrameUnits::Groups,
+                WindowFrameBound::Preceding(ScalarValue::UInt64(Some(1))),
+                WindowFrameBound::CurrentRow,
+            )
+            .with_exclusion(WindowFrameExclusion::Ties),
+            // Offsets coerced to the ORDER BY type:
+            WindowFrame::new_bounds(
+                WindowFrameUnits::Range,
+                WindowFrameBound::Preceding(ScalarValue::Decimal128(Some(150), 10, 2)),
+                WindowFrameBound::Following(ScalarValue::Decimal128(None, 10, 2)),
+            ),
+            WindowFrame::new_bounds(
+                WindowFrameUnits::Range,
+                WindowFrameBound::Preceding(ScalarValue::new_interval_mdn(0, 1, 0)),
+                WindowFrameBound::Following(ScalarValue::new_interval_mdn(1, 2, 3)),
+            ),
+        ];
+        for frame in frames {
+            let json = serde_json::to_string(&frame).unwrap();
+            let deserialized: WindowFrame = serde_json::from_str(&json).unwrap();
+            assert_eq!(deserialized, frame, "{json}");
+            assert_eq!(deserialized.is_causal(), frame.is_causal());
+        }
+
+        // The causal flag is recomputed, not read from the payload:
+        let json = r#"{"units":"Rows","start_bound":"CurrentRow","end_bound":{"Following":{"data_type":"UInt64","value":null}},"causal":true}"#;
+        let frame: WindowFrame = serde_json::from_str(json).unwrap();
+        assert!(!frame.is_causal());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_window_frame_is_provably_empty() {
+        let current_row_frame = |units| {
+            WindowFrame::new_bounds(
+                units,
+                WindowFrameBound::CurrentRow,
+                WindowFrameBound::CurrentRow,
+            )
+        };
+        let empty_frames = [
+            current_row_frame(WindowFrameUnits::Groups)
+                .with_exclusion(WindowFrameExclusion::Group),
+            current_row_frame(WindowFrameUnits::Range)
+                .with_exclusion(WindowFrameExclusion::Group),
+            current_row_frame(WindowFrameUnits::Rows)
+                .with_exclusion(WindowFrameExclusion::Group),
+            current_row_frame(WindowFrameUnits::Rows)
+                .with_exclusion(WindowFrameExclusion::CurrentRow),
+            WindowFrame::new_bounds(
+                WindowFrameUnits::Rows,
+                WindowFrameBound::Preceding(ScalarValue::UInt64(Some(0))),
+                WindowFrameBound::Following(ScalarValue::UInt64(Some(0))),
+            )
+            .with_exclusion(WindowFrameExclusion::CurrentRow),
+        ];
+        for frame in empty_frames {
+            assert!(frame.is_provably_empty(), "{frame}");
+        }
+
+        let non_empty_frames = [
+            WindowFrame::new(None),
+            WindowFrame::new(Some(false)),
+            current_row_frame(WindowFrameUnits::Groups),
+            // Peers of the current row remain in the frame:
+            current_row_frame(WindowFrameUnits::Groups)
+                .with_exclusion(WindowFrameExclusion::CurrentRow),
+            current_row_frame(WindowFrameUnits::Rows)
+                .with_exclusion(WindowFrameExclusion::Ties),
+            WindowFrame::new_bounds(
+                WindowFrameUnits::Rows,
+                WindowFrameBound::Preceding(ScalarValue::UInt64(Some(1))),
+                WindowFrameBound::CurrentRow,
+            )
+            .with_exclusion(WindowFrameExclusion::CurrentRow),
+            WindowFrame::new_bounds(
+                WindowFrameUnits::Groups,
+                WindowFrameBound::CurrentRow,
+                WindowFrameBound::Following(ScalarValue::UInt64(None)),
+            )
+            .with_exclusion(WindowFrameExclusion::Group),
+        ];
+        for frame in non_empty_frames {
+            assert!(!frame.is_provably_empty(), "{frame}");
+        }
+    }
+
+    #[test]
+    fn test_window_frame_bound_cmp_position() {
+        use Ordering::*;
+
+        let preceding = |v| WindowFrameBound::Preceding(ScalarValue::UInt64(v));
+        let following = |v| WindowFrameBound::Following(ScalarValue::UInt64(v));
+        let utf8 = |v: &str| ScalarValue::Utf8(Some(v.to_string()));
+
+        // Bounds in past-to-future order:
+        let ordered = [
+            preceding(None),
+            preceding(Some(10)),
+            preceding(Some(2)),
+            preceding(Some(1)),
+            WindowFrameBound::CurrentRow,
+            following(Some(1)),
+            following(Some(2)),
+            following(Some(10)),
+            following(None),
+        ];
+        for (i, lhs) in ordered.iter().enumerate() {
+            for (j, rhs) in ordered.iter().enumerate() {
+                assert_eq!(lhs.cmp_position(rhs), Some(i.cmp(&j)), "{lhs} vs {rhs}");
+            }
+        }
+
+        // Unbounded and zero offsets are positioned regardless of their type:
+        assert_eq!(
+            WindowFrameBound::Preceding(ScalarValue::Int64(None))
+                .cmp_position(&preceding(None)),
+            Some(Equal)
+        );
+        assert_eq!(
+            preceding(Some(0)).cmp_position(&WindowFrameBound::CurrentRow),
+            Some(Equal)
+        );
+        assert_eq!(
+            following(Some(0)).cmp_position(&preceding(Some(1))),
+            Some(Greater)
+        );
+        assert_eq!(
+            WindowFrameBound::Following(ScalarValue::Float64(Some(0.5))).cmp_position(
+                &WindowFrameBound::Following(ScalarValue::Float64(Some(1.5)))
+            ),
+            Some(Less)
+        );
+
+        // Offsets of different kinds can still be ordered:
+        assert_eq!(
+            WindowFrameBound::Preceding(utf8("1"))
+                .cmp_position(&WindowFrameBound::Following(utf8("1"))),
+            Some(Less)
+        );
+        assert_eq!(
+            WindowFrameBound::Preceding(utf8("1"))
+                .cmp_position(&WindowFrameBound::Preceding(utf8("1"))),
+            Some(Equal)
+        );
+
+        // Incomparable offsets:
+        assert_eq!(
+            WindowFrameBound::Preceding(utf8("10"))
+                .cmp_position(&WindowFrameBound::Preceding(utf8("9"))),
+            None
+        );
+        assert_eq!(
+            preceding(Some(1))
+                .cmp_position(&WindowFrameBound::Preceding(ScalarValue::Int64(Some(2)))),
+            None
+        );
+    }
+
+    #[test]
+    fn test_window_frame_bound_shift_by() -> Result<()> {
+        let preceding = |v| WindowFrameBound::Preceding(ScalarValue::UInt64(v));
+        let following = |v| WindowFrameBound::Following(ScalarValue::UInt64(v));
+
+        assert_eq!(preceding(Some(1)).shift_by(-2)?, preceding(Some(3)));
+        assert_eq!(preceding(Some(1)).shift_by(0)?, preceding(Some(1)));
+        assert_eq!(following(Some(4)).shift_by(-1)?, following(Some(3)));
+        // Shifting past the current row flips the direction:
+        assert_eq!(preceding(Some(1)).shift_by(3)?, following(Some(2)));
+        assert_eq!(following(Some(1)).shift_by(-3)?, preceding(Some(2)));
+        // Landing on the current row keeps the kind of the bound:
+        assert_eq!(preceding(Some(2)).shift_by(2)?, preceding(Some(0)));
+        assert_eq!(following(Some(2)).shift_by(-2)?, following(Some(0)));
+        // Other integer types are accepted and kept, e.g. for coerced RANGE
+        // offsets:
+        let bound = WindowFrameBound::Preceding(ScalarValue::Int64(Some(1)));
+        assert_eq!(
+            bound.shift_by(-1)?,
+            WindowFrameBound::Preceding(ScalarValue::Int64(Some(2)))
+        );
+        let bound = WindowFrameBound::Preceding(ScalarValue::Int32(Some(1)));
+        assert_eq!(
+            bound.shift_by(3)?,
+            WindowFrameBound::Following(ScalarValue::Int32(Some(2)))
+        );
+        let bound = WindowFrameBound::Following(ScalarValue::Int8(Some(127)));
+        assert!(bound.shift_by(1).is_err());
+
+        // CURRENT ROW and unbounded bounds are unchanged:
+        for bound in [
+            WindowFrameBound::CurrentRow,
+            preceding(None),
+            following(None),
+        ] {
+            assert_eq!(bound.shift_by(5)?, bound);
+        }
+
+        // Non-integer offsets cannot be shifted:
+        let bound = WindowFrameBound::Preceding(ScalarValue::Utf8(Some("1".into())));
+        assert_eq!(
+            bound.shift_by(1).unwrap_err().strip_backtrace(),
+            "Error during planning: Cannot shift window frame bound 1 PRECEDING: offset is not an integer"
+        );
+        let bound = WindowFrameBound::Following(ScalarValue::Float64(Some(1.5)));
+        assert!(bound.shift_by(1).is_err());
+        assert!(following(Some(u64::MAX)).shift_by(1).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_window_frame_bound_as_range_scalar() -> Result<()> {
+        use arrow::datatypes::{IntervalMonthDayNano, IntervalUnit, TimeUnit};
+
+        let utf8 = |v: &str| ScalarValue::Utf8(Some(v.to_string()));
+        let preceding = WindowFrameBound::Preceding;
+        let following = WindowFrameBound::Following;
+
+        // Numeric offsets:
+        assert_eq!(
+            preceding(utf8("10")).as_range_scalar(&DataType::Int64)?,
+            ScalarValue::Int64(Some(10))
+        );
+        assert_eq!(
+            following(utf8("1.5")).as_range_scalar(&DataType::Float64)?,
+            ScalarValue::Float64(Some(1.5))
+        );
+        assert_eq!(
+            preceding(ScalarValue::UInt64(Some(3))).as_range_scalar(&DataType::Int32)?,
+            ScalarValue::Int32(Some(3))
+        );
+        let dictionary =
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::UInt32));
+        assert_eq!(
+            preceding(utf8("7")).as_range_scalar(&dictionary)?,
+            ScalarValue::UInt32(Some(7))
+        );
+
+        // Interval offsets:
+        let timestamp = DataType::Timestamp(TimeUnit::Nanosecond, None);
+        assert_eq!(
+            preceding(utf8("1 DAY")).as_range_scalar(&timestamp)?,
+            ScalarValue::IntervalMonthDayNano(Some(IntervalMonthDayNano::new(0, 1, 0)))
+        );
+        assert_eq!(
+            following(utf8("2 MONTHS")).as_range_scalar(&DataType::Date32)?,
+            ScalarValue::IntervalMonthDayNano(Some(IntervalMonthDayNano::new(2, 0, 0)))
+        );
+
+        // Unbounded bounds and CURRENT ROW:
+        assert_eq!(
+            following(ScalarValue::UInt64(None)).as_range_scalar(&DataType::Int64)?,
+            ScalarValue::Int64(None)
+        );
+        assert_eq!(
+            WindowFrameBound::CurrentRow.as_range_scalar(&timestamp)?,
+            ScalarValue::new_zero(&DataType::Interval(IntervalUnit::MonthDayNano))?
+        );
+
+        // Invalid offsets:
+        assert!(
+            preceding(utf8("abc"))
+                .as_range_scalar(&DataType::Int64)
+                .is_err()
+        );
+        assert!(
+            preceding(utf8("1 DAY"))
+                .as_range_scalar(&DataType::Int64)
+                .is_err()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_window_frame_normalize_bounds() {
+        use std::hash::DefaultHasher;
+
+        let hash = |frame: &WindowFrame| {
+            let mut hasher = DefaultHasher::new();
+            frame.hash(&mut hasher);
+            hasher.finish()
+        };
+        let frame = |units, start, end| WindowFrame::new_bounds(units, start, end);
+        let preceding = |v| WindowFrameBound::Preceding(v);
+        let following = |v| WindowFrameBound::Following(v);
+        let utf8 = |v: &str| ScalarValue::Utf8(Some(v.to_string()));
+        let current_row = || WindowFrameBound::CurrentRow;
+        use WindowFrameUnits::*;
+
+        let equal_pairs = [
+            // Zero offsets vs CURRENT ROW:
+            (
+                frame(Rows, preceding(ScalarValue::UInt64(Some(0))), current_row()),
+                frame(Rows, current_row(), current_row()),
+            ),
+            (
+                frame(Range, preceding(utf8("1")), following(utf8("0"))),
+                frame(Range, preceding(utf8("1")), current_row()),
+            ),
+            // Redundant zeros in untyped RANGE offsets:
+            (
+                frame(Range, preceding(utf8("01")), current_row()),
+                frame(Range, preceding(utf8("1")), current_row()),
+            ),
+            (
+                frame(Range, current_row(), following(utf8("001.50"))),
+                frame(Range, current_row(), following(utf8("1.5"))),
+            ),
+            // Synthesized vs explicit whole partition frames:
+            (
+                WindowFrame::new(None),
+                frame(
+                    Range,
+                    preceding(ScalarValue::UInt64(None)),
+                    following(ScalarValue::UInt64(None)),
+                ),
+            ),
+            (
+                WindowFrame::rows(None, None),
+                frame(
+                    Groups,
+                    preceding(ScalarValue::Int64(None)),
+                    following(ScalarValue::Utf8(None)),
+                ),
+            ),
+            // GROUPS and RANGE frames both include whole peer groups:
+            (
+                WindowFrame::new(Some(false)),
+                frame(Groups, preceding(ScalarValue::UInt64(None)), current_row()),
+            ),
+            // Typed zero offsets:
+            (
+                frame(
+                    Range,
+                    preceding(ScalarValue::Int32(Some(5))),
+                    following(ScalarValue::Int32(Some(0))),
+                ),
+                frame(Range, preceding(ScalarValue::Int32(Some(5))), current_row()),
+            ),
+        ];
+        for (lhs, rhs) in equal_pairs {
+            assert_ne!(lhs, rhs, "{lhs} vs {rhs}");
+            assert_eq!(
+                lhs.normalize_bounds(),
+                rhs.normalize_bounds(),
+                "{lhs} vs {rhs}"
+            );
+            assert_eq!(
+                hash(&lhs.normalize_bounds()),
+                hash(&rhs.normalize_bounds()),
+                "{lhs} vs {rhs}"
+            );
+            // Normalization is idempotent and keeps causality consistent:
+            let normalized = lhs.normalize_bounds();
+            assert_eq!(normalized.normalize_bounds(), normalized);
+            assert_eq!(normalized.is_causal(), rhs.normalize_bounds().is_causal());
+        }
+
+        let different_pairs = [
+            (
+                frame(Range, preceding(utf8("1")), current_row()),
+                frame(Range, preceding(utf8("2")), current_row()),
+            ),
+            (
+                frame(Range, preceding(utf8("1")), current_row()),
+                frame(Range, preceding(utf8("1.0")), current_row()),
+            ),
+            // Only GROUPS frames over whole peer groups are equal to RANGE:
+            (
+                frame(
+                    Groups,
+                    preceding(ScalarValue::UInt64(Some(1))),
+                    current_row(),
+                ),
+                frame(
+                    Range,
+                    preceding(ScalarValue::UInt64(Some(1))),
+                    current_row(),
+                ),
+            ),
+            // ROWS frames do not include peers:
+            (WindowFrame::new(Some(true)), WindowFrame::new(Some(false))),
+            (
+                WindowFrame::rows(None, None),
+                WindowFrame::rows(None, None).with_exclusion(WindowFrameExclusion::Ties),
+            ),
+        ];
+        for (lhs, rhs) in different_pairs {
+            assert_ne!(
+                lhs.normalize_bounds(),
+                rhs.normalize_bounds(),
+                "{lhs} vs {rhs}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_window_frame_is_reversal_safe() {
+        let preceding = |n| WindowFrameBound::Preceding(ScalarValue::UInt64(n));
+        let following = |n| WindowFrameBound::Following(ScalarValue::UInt64(n));
+        let frame = |units, start, end| WindowFrame::new_bounds(units, start, end);
+        let cases = [
+            // ROWS frames are always safe:
+            (WindowFrame::rows(Some(3), Some(1)), true),
+            (WindowFrame::rows(None, Some(0)), true),
+            // Symmetric RANGE / GROUPS frames are safe:
+            (
+                frame(
+                    WindowFrameUnits::Range,
+                    preceding(Some(2)),
+                    following(Some(2)),
+                ),
+                true,
+            ),
+            (
+                frame(WindowFrameUnits::Groups, preceding(None), following(None)),
+                true,
+            ),
+            (
+                frame(
+                    WindowFrameUnits::Range,
+                    WindowFrameBound::CurrentRow,
+                    WindowFrameBound::CurrentRow,
+                ),
+                true,
+            ),
+            // Asymmetric RANGE / GROUPS frames are not:
+            (WindowFrame::new(Some(false)), false),
+            (
+                frame(
+                    WindowFrameUnits::Range,
+                    preceding(Some(3)),
+                    following(Some(1)),
+                ),
+                false,
+            ),
+            (
+                frame(
+                    WindowFrameUnits::Groups,
+                    preceding(Some(1)),
+                    following(None),
+                ),
+                false,
+            ),
+        ];
+        for (frame, expected) in cases {
+            assert_eq!(frame.is_reversal_safe(), expected, "{frame}");
+        }
+    }
+
+    #[test]
+    fn test_window_frame_reverse() {
+        let preceding = |n| WindowFrameBound::Preceding(ScalarValue::UInt64(n));
+        let following = |n| WindowFrameBound::Following(ScalarValue::UInt64(n));
+        let frames = [
+            (WindowFrame::new(None), true),
+            (WindowFrame::new(Some(true)), false),
+            (WindowFrame::new(Some(false)), false),
+            (
+                WindowFrame::new_bounds(
+                    WindowFrameUnits::Rows,
+                    preceding(Some(3)),
+                    following(Some(2)),
+                ),
+                false,
+            ),
+            (
+                WindowFrame::new_bounds(
+                    WindowFrameUnits::Rows,
+                    preceding(Some(2)),
+                    following(Some(2)),
+                ),
+                true,
+            ),
+            (
+                WindowFrame::new_bounds(
+                    WindowFrameUnits::Rows,
+                    preceding(Some(5)),
+                    preceding(Some(1)),
+                ),
+                false,
+            ),
+            (
+                WindowFrame::new_bounds(
+                    WindowFrameUnits::Groups,
+                    following(Some(1)),
+                    following(None),
+                ),
+                false,
+            ),
+            (
+                WindowFrame::new_bounds(
+                    WindowFrameUnits::Groups,
+                    preceding(Some(0)),
+                    WindowFrameBound::CurrentRow,
+                ),
+                true,
+            ),
+            (
+                WindowFrame::new_bounds(
+                    WindowFrameUnits::Range,
+                    WindowFrameBound::Preceding(ScalarValue::Utf8(Some("1 DAY".into()))),
+                    WindowFrameBound::Following(ScalarValue::Utf8(Some("1 DAY".into()))),
+                ),
+                true,
+            ),
+            (
+                WindowFrame::new_bounds(
+                    WindowFrameUnits::Range,
+                    WindowFrameBound::CurrentRow,
+                    WindowFrameBound::Following(ScalarValue::Utf8(Some("1 DAY".into()))),
+                ),
+                false,
+            ),
+        ];
+        for (frame, symmetric) in frames {
+            assert_eq!(frame.reverse().reverse(), frame, "{frame}");
+            assert_eq!(frame.is_symmetric(), symmetric, "{frame}");
+        }
+
+        // Exclusions are preserved:
+        for exclusion in [
+            WindowFrameExclusion::NoOthers,
+            WindowFrameExclusion::CurrentRow,
+            WindowFrameExclusion::Group,
+            WindowFrameExclusion::Ties,
+        ] {
+            let frame = WindowFrame::new_bounds(
+                WindowFrameUnits::Groups,
+                preceding(Some(3)),
+                following(Some(1)),
+            )
+            .with_exclusion(exclusion);
+            let reversed = frame.reverse();
+            assert_eq!(reversed.exclusion, exclusion, "{frame}");
+            assert_eq!(reversed.start_bound, preceding(Some(1)));
+            assert_eq!(reversed.end_bound, following(Some(3)));
+            assert_eq!(reversed.reverse(), frame, "{frame}");
+        }
+    }
+
+    #[test]
+    fn test_window_frame_from_str_exclusion() -> Result<()> {
+        let cases = [
+            (
+                "ROWS BETWEEN 1 PRECEDING AND CURRENT ROW",
+                WindowFrameExclusion::NoOthers,
+            ),
+            (
+                "ROWS BETWEEN 1 PRECEDING AND CURRENT ROW EXCLUDE NO OTHERS",
+                WindowFrameExclusion::NoOthers,
+            ),
+            (
+                "ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING EXCLUDE CURRENT ROW",
+                WindowFrameExclusion::CurrentRow,
+            ),
+            (
+                "range between unbounded preceding and current row exclude group",
+                WindowFrameExclusion::Group,
+            ),
+            (
+                "GROUPS 2 PRECEDING EXCLUDE TIES",
+                WindowFrameExclusion::Ties,
+            ),
+        ];
+        for (sql, exclusion) in cases {
+            let frame: WindowFrame = sql.parse()?;
+            assert_eq!(frame.exclusion, exclusion, "{sql}");
+        }
+
+        let frame: WindowFrame =
+            "ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING EXCLUDE TIES".parse()?;
+        let expected = WindowFrame::new_bounds(
+            WindowFrameUnits::Rows,
+            WindowFrameBound::Preceding(ScalarValue::UInt64(Some(1))),
+            WindowFrameBound::Following(ScalarValue::UInt64(Some(1))),
+        )
+        .with_exclusion(WindowFrameExclusion::Ties);
+        assert_eq!(frame, expected);
+        assert_eq!(frame.to_string().parse::<WindowFrame>()?, frame);
+
+        for sql in [
+            "ROWS BETWEEN 1 PRECEDING AND CURRENT ROW EXCLUDE OTHERS",
+            "ROWS BETWEEN 1 PRECEDING AND CURRENT ROW EXCLUDE",
+            "ROWS BETWEEN 1 PRECEDING AND CURRENT ROW TIES",
+        ] {
+            assert!(sql.parse::<WindowFrame>().is_err(), "{sql}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_window_frame_offset_error_diagnostic() {
+        use datafusion_common::{Location, Span};
+        use sqlparser::{dialect::GenericDialect, parser::Parser};
+
+        let window_frame = Parser::new(&GenericDialect {})
+            .try_with_sql("ROWS -3 PRECEDING")
+            .unwrap()
+            .parse_window_frame()
+            .unwrap();
+        let err = WindowFrame::try_from(window_frame).unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Error during planning: Invalid window frame: frame offsets for ROWS / GROUPS must be non negative integers"
+        );
+
+        let diagnostic = err.diagnostic().expect("error should have a diagnostic");
+        assert_eq!(
+            diagnostic.message,
+            "Invalid window frame: frame offsets for ROWS / GROUPS must be non negative integers"
+        );
+        assert_eq!(
+            diagnostic.span,
+            Some(Span::new(
+                Location { line: 1, column: 7 },
+                Location { line: 1, column: 8 },
+            ))
+        );
+    }
+
+    #[test]
+    fn test_window_frame_bound_parse_with_type() -> Result<()> {
+        let number = |value: &str| {
+            Some(Box::new(ast::Expr::value(ast::Value::Number(
+                value.to_string(),
+                false,
+            ))))
+        };
+        let negated = |value: &str| {
+            Some(Box::new(ast::Expr::UnaryOp {
+                op: ast::UnaryOperator::Minus,
+                expr: number(value).unwrap(),
+            }))
+        };
+        let range = ast::WindowFrameUnits::Range;
+
+        // Without a type hint, RANGE offsets are kept as strings:
+        let bound = WindowFrameBound::try_parse_with_type(
+            ast::WindowFrameBound::Preceding(number("10")),
+            &range,
+            None,
+        )?;
+        assert_eq!(
+            bound,
+            WindowFrameBound::Preceding(ScalarValue::Utf8(Some("10".to_string())))
+        );
+
+        // With a type hint, RANGE offsets are typed:
+        let preceding = WindowFrameBound::try_parse_with_type(
+            ast::WindowFrameBound::Preceding(number("10")),
+            &range,
+            Some(&DataType::Int64),
+        )?;
+        assert_eq!(
+            preceding,
+            WindowFrameBound::Preceding(ScalarValue::Int64(Some(10)))
+        );
+        let following = WindowFrameBound::try_parse_with_type(
+            ast::WindowFrameBound::Following(number("1.5")),
+            &range,
+            Some(&DataType::Float64),
+        )?;
+        assert_eq!(
+            following,
+            WindowFrameBound::Following(ScalarValue::Float64(Some(1.5)))
+        );
+
+        // Negative offsets are reconciled with the opposite direction:
+        let negative_following = WindowFrameBound::try_parse_with_type(
+            ast::WindowFrameBound::Following(negated("10")),
+            &range,
+            Some(&DataType::Int64),
+        )?;
+        assert_eq!(negative_following, preceding);
+        let negative_preceding = WindowFrameBound::try_parse_with_type(
+            ast::WindowFrameBound::Preceding(negated("10")),
+            &range,
+            Some(&DataType::Int64),
+        )?;
+        assert_eq!(
+            negative_preceding,
+            WindowFrameBound::Following(ScalarValue::Int64(Some(10)))
+        );
+
+        // ROWS offsets are unaffected by the type hint:
+        let bound = WindowFrameBound::try_parse_with_type(
+            ast::WindowFrameBound::Preceding(number("10")),
+            &ast::WindowFrameUnits::Rows,
+            Some(&DataType::Int64),
+        )?;
+        assert_eq!(
+            bound,
+            WindowFrameBound::Preceding(ScalarValue::UInt64(Some(10)))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_window_frame_causal_with_typed_range_offsets() -> Result<()> {
+        let interval = |value: &str| {
+            Some(Box::new(ast::Expr::Interval(ast::Interval {
+                value: Box::new(ast::Expr::value(ast::Value::SingleQuotedString(
+                    value.to_string(),
+                ))),
+                leading_field: Some(ast::DateTimeField::Day),
+                leading_precision: None,
+                last_field: None,
+                fractional_seconds_precision: None,
+            })))
+        };
+        let range = ast::WindowFrameUnits::Range;
+        let timestamp = DataType::Timestamp(arrow::datatypes::TimeUnit::Nanosecond, None);
+        let frame = |end_bound: ast::WindowFrameBound, data_type: Option<&DataType>| {
+            let start_bound = WindowFrameBound::try_parse_with_type(
+                ast::WindowFrameBound::Preceding(None),
+                &range,
+                data_type,
+            )?;
+            let end_bound =
+                WindowFrameBound::try_parse_with_type(end_bound, &range, data_type)?;
+            Ok::<_, datafusion_common::DataFusionError>(WindowFrame::new_bounds(
+                WindowFrameUnits::Range,
+                start_bound,
+                end_bound,
+            ))
+        };
+
+        // RANGE BETWEEN UNBOUNDED PRECEDING AND INTERVAL '1' DAY PRECEDING:
+        let preceding = ast::WindowFrameBound::Preceding(interval("1"));
+        let typed = frame(preceding.clone(), Some(&timestamp))?;
+        assert_eq!(
+            typed.end_bound,
+            WindowFrameBound::Preceding(ScalarValue::new_interval_mdn(0, 1, 0))
+        );
+        assert!(typed.is_causal());
+        // Untyped offsets are stored as strings, so causality can't be derived:
+        assert!(!frame(preceding, None)?.is_causal());
+
+        // RANGE BETWEEN UNBOUNDED PRECEDING AND INTERVAL '1' DAY FOLLOWING:
+        let following = ast::WindowFrameBound::Following(interval("1"));
+        let typed = frame(following, Some(&timestamp))?;
+        assert_eq!(
+            typed.end_bound,
+            WindowFrameBound::Following(ScalarValue::new_interval_mdn(0, 1, 0))
+        );
+        assert!(!typed.is_causal());
         Ok(())
     }
+
+    #[test]
+    fn test_window_frame_sliding_past() {
+        let preceding =
+            |n: u64| WindowFrameBound::Preceding(ScalarValue::UInt64(Some(n)));
+        let following =
+            |n: u64| WindowFrameBound::Following(ScalarValue::UInt64(Some(n)));
+        let unbounded_preceding = WindowFrameBound::Preceding(ScalarValue::UInt64(None));
+        let rows =
+            |start, end| WindowFrame::new_bounds(WindowFrameUnits::Rows, start, end);
+
+        // ROWS BETWEEN 5 PRECEDING AND 2 PRECEDING:
+        let frame = rows(preceding(5), preceding(2));
+        assert!(frame.is_causal());
+        assert!(frame.is_sliding());
+        assert!(!frame.contains_current_row());
+        assert_eq!(frame.fixed_rows_len(), Some(4));
+        assert_eq!(frame.frame_size(), Some(4));
+
+        // ROWS BETWEEN 2 PRECEDING AND 1 FOLLOWING:
+        let frame = rows(preceding(2), following(1));
+        assert!(!frame.is_causal());
+        assert!(frame.is_sliding());
+        assert!(frame.contains_current_row());
+        assert_eq!(frame.fixed_rows_len(), Some(4));
+        assert_eq!(frame.frame_size(), Some(4));
+
+        // ROWS BETWEEN 1 FOLLOWING AND 3 FOLLOWING:
+        let frame = rows(following(1), following(3));
+        assert!(!frame.contains_current_row());
+        assert_eq!(frame.fixed_rows_len(), Some(3));
+        assert_eq!(frame.frame_size(), Some(3));
+
+        // ROWS BETWEEN CURRENT ROW AND 0 FOLLOWING:
+        let frame = rows(WindowFrameBound::CurrentRow, following(0));
+        assert!(frame.is_causal());
+        assert!(frame.contains_current_row());
+        assert_eq!(frame.fixed_rows_len(), Some(1));
+        assert_eq!(frame.frame_size(), Some(1));
+
+        // ROWS BETWEEN UNBOUNDED PRECEDING AND 2 PRECEDING:
+        let frame = rows(unbounded_preceding.clone(), preceding(2));
+        assert!(frame.is_causal());
+        assert!(!frame.is_sliding());
+        assert!(!frame.contains_current_row());
+        assert_eq!(frame.fixed_rows_len(), None);
+        assert_eq!(frame.frame_size(), None);
+
+        // ROWS BETWEEN 3 PRECEDING AND UNBOUNDED FOLLOWING:
+        let frame = rows(
+            preceding(3),
+            WindowFrameBound::Following(ScalarValue::UInt64(None)),
+        );
+        assert!(!frame.is_sliding());
+        assert_eq!(frame.frame_size(), None);
+
+        // ROWS BETWEEN 10 PRECEDING AND 10 FOLLOWING:
+        assert_eq!(rows(preceding(10), following(10)).frame_size(), Some(21));
+
+        // ROWS BETWEEN 2 PRECEDING AND CURRENT ROW EXCLUDE CURRENT ROW:
+        let frame = rows(preceding(2), WindowFrameBound::CurrentRow)
+            .with_exclusion(WindowFrameExclusion::CurrentRow);
+        assert!(!frame.includes_current_row());
+        assert_eq!(frame.fixed_rows_len(), Some(3));
+        assert_eq!(frame.frame_size(), Some(2));
+
+        // The current row is not in the frame to begin with:
+        let frame = rows(preceding(5), preceding(2))
+            .with_exclusion(WindowFrameExclusion::CurrentRow);
+        assert_eq!(frame.frame_size(), Some(4));
+
+        // The number of peers excluded depends on the data:
+        for exclusion in [WindowFrameExclusion::Group, WindowFrameExclusion::Ties] {
+            let frame = rows(preceding(2), following(1)).with_exclusion(exclusion);
+            assert_eq!(frame.frame_size(), None, "{frame}");
+        }
+
+        // RANGE frames do not have a fixed number of rows:
+        let frame = WindowFrame::new_bounds(
+            WindowFrameUnits::Range,
+            WindowFrameBound::Preceding(ScalarValue::Int64(Some(5))),
+            WindowFrameBound::Preceding(ScalarValue::Int64(Some(2))),
+        );
+        assert!(frame.is_causal());
+        assert!(frame.is_sliding());
+        assert!(!frame.contains_current_row());
+        assert_eq!(frame.fixed_rows_len(), None);
+        assert_eq!(frame.frame_size(), None);
+        assert!(WindowFrame::new(Some(false)).contains_current_row());
+    }
+
+    #[test]
+    fn test_compute_causal() {
+        use WindowFrameUnits::*;
+
+        let preceding = |v| WindowFrameBound::Preceding(v);
+        let following = |v| WindowFrameBound::Following(v);
+        let current_row = WindowFrameBound::CurrentRow;
+        let uint = |n| ScalarValue::UInt64(n);
+
+        // ROWS frames are causal unless they end after the current row:
+        assert!(compute_causal(Rows, &preceding(uint(None))));
+        assert!(compute_causal(Rows, &preceding(uint(Some(1)))));
+        assert!(compute_causal(Rows, &current_row));
+        assert!(compute_causal(Rows, &following(uint(Some(0)))));
+        assert!(!compute_causal(Rows, &following(uint(Some(1)))));
+        assert!(!compute_causal(Rows, &following(uint(None))));
+
+        // RANGE / GROUPS frames must end before the peers of the current row:
+        for units in [Range, Groups] {
+            assert!(compute_causal(units, &preceding(uint(None))));
+            assert!(compute_causal(units, &preceding(uint(Some(1)))));
+            assert!(!compute_causal(units, &preceding(uint(Some(0)))));
+            assert!(!compute_causal(units, &current_row));
+            assert!(!compute_causal(units, &following(uint(Some(1)))));
+            assert!(!compute_causal(units, &following(uint(None))));
+        }
+
+        // Untyped RANGE offsets cannot be compared against zero:
+        let utf8 = ScalarValue::Utf8(Some("1".to_string()));
+        assert!(!compute_causal(Range, &preceding(utf8)));
+
+        // The causality derived by `new_bounds` agrees:
+        let frame = WindowFrame::new_range(
+            preceding(uint(None)),
+            preceding(ScalarValue::Int64(Some(2))),
+        );
+        assert_eq!(
+            frame.is_causal(),
+            compute_causal(frame.units, &frame.end_bound)
+        );
+    }
+
+    #[test]
+    fn test_window_frame_units_classification() {
+        assert!(WindowFrameUnits::Rows.is_row_based());
+        assert!(!WindowFrameUnits::Rows.is_value_based());
+        assert!(WindowFrameUnits::Groups.is_row_based());
+        assert!(!WindowFrameUnits::Groups.is_value_based());
+        assert!(!WindowFrameUnits::Range.is_row_based());
+        assert!(WindowFrameUnits::Range.is_value_based());
+    }
+
+    #[test]
+    fn test_window_frame_units_try_from_str() -> Result<()> {
+        for (value, expected) in [
+            ("ROWS", WindowFrameUnits::Rows),
+            ("rows", WindowFrameUnits::Rows),
+            ("Range", WindowFrameUnits::Range),
+            ("gRoUpS", WindowFrameUnits::Groups),
+        ] {
+            assert_eq!(WindowFrameUnits::try_from(value)?, expected);
+        }
+        // Units round-trip through their display form:
+        for units in [
+            WindowFrameUnits::Rows,
+            WindowFrameUnits::Range,
+            WindowFrameUnits::Groups,
+        ] {
+            assert_eq!(
+                WindowFrameUnits::try_from(units.to_string().as_str())?,
+                units
+            );
+        }
+
+        let err = WindowFrameUnits::try_from("BUCKETS").unwrap_err();
+        assert_eq!(
+            err.strip_backtrace(),
+            "Error during planning: Invalid window frame units: BUCKETS"
+        );
+        assert!(WindowFrameUnits::try_from(" ROWS").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_window_frame_eq_ignores_causal() {
+        let frame = WindowFrame::new_rows(
+            WindowFrameBound::Preceding(ScalarValue::UInt64(Some(1))),
+            WindowFrameBound::CurrentRow,
+        );
+        assert!(frame.is_causal());
+        let mut other = frame.clone();
+        other.causal = false;
+        assert_eq!(frame, other);
+        assert_eq!(frame.partial_cmp(&other), Some(Ordering::Equal));
+
+        // Other fields are still compared:
+        let other = frame.clone().with_exclusion(WindowFrameExclusion::Ties);
+        assert_ne!(frame, other);
+        assert_eq!(frame.partial_cmp(&other), Some(Ordering::Less));
+        let other = WindowFrame::new_groups(
+            WindowFrameBound::Preceding(ScalarValue::UInt64(Some(1))),
+            WindowFrameBound::CurrentRow,
+        );
+        assert_ne!(frame, other);
+    }
+
+    #[test]
+    fn test_window_frame_units_constructors() {
+        let start = WindowFrameBound::Preceding(ScalarValue::UInt64(Some(1)));
+        let end = WindowFrameBound::CurrentRow;
+        for (frame, units) in [
+            (
+                WindowFrame::new_rows(start.clone(), end.clone()),
+                WindowFrameUnits::Rows,
+            ),
+            (
+                WindowFrame::new_range(start.clone(), end.clone()),
+                WindowFrameUnits::Range,
+            ),
+            (
+                WindowFrame::new_groups(start.clone(), end.clone()),
+                WindowFrameUnits::Groups,
+            ),
+        ] {
+            assert_eq!(frame.units, units);
+            assert_eq!(
+                frame,
+                WindowFrame::new_bounds(units, start.clone(), end.clone())
+            );
+        }
+    }
+
+    #[test]
+    fn test_window_frame_includes_current_row() {
+        let preceding = |n| WindowFrameBound::Preceding(ScalarValue::UInt64(n));
+        let following = |n| WindowFrameBound::Following(ScalarValue::UInt64(n));
+        let current_row = || WindowFrameBound::CurrentRow;
+
+        let including_frames = [
+            WindowFrame::new(None),
+            WindowFrame::new(Some(false)),
+            WindowFrame::new_groups(preceding(Some(1)), following(Some(1))),
+            WindowFrame::new_range(current_row(), following(None)),
+            WindowFrame::new_rows(preceding(Some(2)), following(Some(0))),
+            WindowFrame::new_groups(preceding(None), current_row())
+                .with_exclusion(WindowFrameExclusion::Ties),
+        ];
+        for frame in including_frames {
+            assert!(frame.includes_current_row(), "{frame}");
+        }
+
+        let excluding_frames = [
+            WindowFrame::new_groups(following(Some(2)), following(Some(3))),
+            WindowFrame::new_range(preceding(None), preceding(Some(1))),
+            WindowFrame::new_rows(preceding(Some(5)), preceding(Some(2))),
+            WindowFrame::new_rows(preceding(Some(1)), following(Some(1)))
+                .with_exclusion(WindowFrameExclusion::CurrentRow),
+            WindowFrame::new_groups(preceding(None), following(None))
+                .with_exclusion(WindowFrameExclusion::Group),
+        ];
+        for frame in excluding_frames {
+            assert!(!frame.includes_current_row(), "{frame}");
+        }
+    }
+
+    #[test]
+    fn test_window_frame_requires_full_partition() {
+        let preceding = |n| WindowFrameBound::Preceding(ScalarValue::UInt64(n));
+        let following = |n| WindowFrameBound::Following(ScalarValue::UInt64(n));
+
+        let full_frames = [
+            WindowFrame::new(None),
+            WindowFrame::new_rows(preceding(None), following(None)),
+            WindowFrame::new_range(WindowFrameBound::CurrentRow, following(None)),
+            WindowFrame::new_groups(preceding(Some(1)), following(None)),
+        ];
+        for frame in full_frames {
+            assert!(frame.requires_full_partition(), "{frame}");
+        }
+
+        let streaming_frames = [
+            WindowFrame::new(Some(false)),
+            WindowFrame::new_rows(preceding(None), WindowFrameBound::CurrentRow),
+            WindowFrame::new_rows(preceding(Some(2)), following(Some(1))),
+            WindowFrame::new_range(preceding(None), following(Some(5))),
+        ];
+        for frame in streaming_frames {
+            assert!(!frame.requires_full_partition(), "{frame}");
+        }
+    }
+
+    #[test]
+    fn test_window_frame_is_running_total() {
+        let unbounded_preceding = || WindowFrameBound::Preceding(ScalarValue::Null);
+        let preceding = |n| WindowFrameBound::Preceding(ScalarValue::UInt64(Some(n)));
+        let following = |n| WindowFrameBound::Following(ScalarValue::UInt64(n));
+        let frame = |units, start, end| WindowFrame::new_bounds(units, start, end);
+        use WindowFrameUnits::*;
+
+        let running_totals = [
+            frame(Rows, unbounded_preceding(), WindowFrameBound::CurrentRow),
+            frame(Range, unbounded_preceding(), WindowFrameBound::CurrentRow),
+            frame(Groups, unbounded_preceding(), WindowFrameBound::CurrentRow),
+            frame(Rows, unbounded_preceding(), following(Some(0))),
+            WindowFrame::new(Some(false)),
+        ];
+        for frame in running_totals {
+            assert!(frame.is_running_total(), "{frame}");
+        }
+
+        let non_running_totals = [
+            frame(Rows, preceding(1), WindowFrameBound::CurrentRow),
+            frame(
+                Rows,
+                WindowFrameBound::CurrentRow,
+                WindowFrameBound::CurrentRow,
+            ),
+            frame(Rows, unbounded_preceding(), preceding(1)),
+            frame(Rows, unbounded_preceding(), following(Some(1))),
+            frame(Rows, unbounded_preceding(), following(None)),
+            frame(Rows, WindowFrameBound::CurrentRow, following(None)),
+            WindowFrame::new(None),
+        ];
+        for frame in non_running_totals {
+            assert!(!frame.is_running_total(), "{frame}");
+        }
+    }
+
+    #[test]
+    fn test_rows_frame_at() {
+        let preceding = |n| WindowFrameBound::Preceding(ScalarValue::UInt64(n));
+        let following = |n| WindowFrameBound::Following(ScalarValue::UInt64(n));
+        let rows =
+            |start, end| WindowFrame::new_bounds(WindowFrameUnits::Rows, start, end);
+
+        let frame = rows(preceding(Some(3)), WindowFrameBound::CurrentRow);
+        // Clamps to the start of the partition:
+        assert_eq!(frame.rows_frame_at(0, 10), Some(0..1));
+        assert_eq!(frame.rows_frame_at(2, 10), Some(0..3));
+        assert_eq!(frame.rows_frame_at(5, 10), Some(2..6));
+
+        let frame = rows(WindowFrameBound::CurrentRow, following(Some(2)));
+        // Clamps to the end of the partition:
+        assert_eq!(frame.rows_frame_at(9, 10), Some(9..10));
+        assert_eq!(frame.rows_frame_at(8, 10), Some(8..10));
+        assert_eq!(frame.rows_frame_at(0, 10), Some(0..3));
+
+        let frame = rows(preceding(None), following(None));
+        assert_eq!(frame.rows_frame_at(0, 10), Some(0..10));
+        assert_eq!(frame.rows_frame_at(9, 10), Some(0..10));
+
+        // Frames entirely before/after the current row:
+        let frame = rows(preceding(Some(5)), preceding(Some(2)));
+        assert_eq!(frame.rows_frame_at(0, 10), Some(0..0));
+        assert_eq!(frame.rows_frame_at(3, 10), Some(0..2));
+        assert_eq!(frame.rows_frame_at(7, 10), Some(2..6));
+        let frame = rows(following(Some(1)), following(Some(3)));
+        assert_eq!(frame.rows_frame_at(9, 10), Some(10..10));
+        assert_eq!(frame.rows_frame_at(0, 10), Some(1..4));
+
+        // Provably empty frames produce empty ranges:
+        let frame = rows(WindowFrameBound::CurrentRow, WindowFrameBound::CurrentRow)
+            .with_exclusion(WindowFrameExclusion::CurrentRow);
+        assert_eq!(frame.rows_frame_at(4, 10), Some(4..4));
+
+        // Out of bounds rows and non-ROWS frames are not supported:
+        assert_eq!(WindowFrame::new(None).rows_frame_at(10, 10), None);
+        assert_eq!(WindowFrame::new(Some(false)).rows_frame_at(0, 10), None);
+    }
 }
@@ -885,7 +885,6 @@ fn coerce_window_frame(
     schema: &DFSchema,
     expressions: &[Sort],
 ) -> Result<WindowFrame> {
-    let mut window_frame = window_frame;
     let target_type = match window_frame.units {
         WindowFrameUnits::Range => {
             let current_types = expressions
@@ -900,10 +899,14 @@ fn coerce_window_frame(
         }
         WindowFrameUnits::Rows | WindowFrameUnits::Groups => DataType::UInt64,
     };
-    window_frame.start_bound =
-        coerce_frame_bound(&target_type, window_frame.start_bound)?;
-    window_frame.end_bound = coerce_frame_bound(&target_type, window_frame.end_bound)?;
-    Ok(window_frame)
+    let start_bound = coerce_frame_bound(&target_type, window_frame.start_bound)?;
+    let end_bound = coerce_frame_bound(&target_type, window_frame.end_bound)?;
+    // Rebuild the frame so that causality is derived from the coerced (typed)
+    // offsets rather than their original `Utf8` placeholders:
+    Ok(
+        WindowFrame::new_bounds(window_frame.units, start_bound, end_bound)
+            .with_exclusion(window_frame.exclusion),
+    )
 }
 
 // Support the `IsTrue` `IsNotTrue` `IsFalse` `IsNotFalse` type coercion.
@@ -1256,7 +1259,7 @@ mod test {
 
     use crate::analyzer::Analyzer;
     use crate::analyzer::type_coercion::{
-        TypeCoercion, TypeCoercionRewriter, coerce_case_expression,
+        TypeCoercion, TypeCoercionRewriter, coerce_case_expression, coerce_window_frame,
     };
     use crate::assert_analyzed_plan_with_config_eq_snapshot;
     use datafusion_common::config::ConfigOptions;
@@ -1268,8 +1271,9 @@ mod test {
     use datafusion_expr::{
         AccumulatorFactoryFunction, AggregateUDF, BinaryExpr, Case, ColumnarValue, Expr,
         ExprSchemable, Filter, LogicalPlan, Operator, ScalarFunctionArgs, ScalarUDF,
-        ScalarUDFImpl, Signature, SimpleAggregateUDF, Subquery, Union, Volatility, cast,
-        col, create_udaf, is_true, lit,
+        ScalarUDFImpl, Signature, SimpleAggregateUDF, Subquery, Union, Volatility,
+        WindowFrame, WindowFrameBound, WindowFrameUnits, cast, col, create_udaf, is_true,
+        lit,
     };
     use datafusion_functions_aggregate::average::AvgAccumulator;
     use datafusion_sql::TableReference;
@@ -1370,6 +1374,38 @@ mod test {
         )
     }
 
+    #[test]
+    fn coerce_window_frame_derives_causality() -> Result<()> {
+        let empty = empty_with_type(DataType::Int64);
+        let order_by = [expr::Sort::new(col("a"), true, false)];
+        let offset = ScalarValue::Utf8(Some("1".to_string()));
+        let unbounded = WindowFrameBound::Preceding(ScalarValue::UInt64(None));
+
+        // RANGE BETWEEN UNBOUNDED PRECEDING AND 1 PRECEDING:
+        let frame = WindowFrame::new_bounds(
+            WindowFrameUnits::Range,
+            unbounded.clone(),
+            WindowFrameBound::Preceding(offset.clone()),
+        );
+        assert!(!frame.is_causal());
+        let frame = coerce_window_frame(frame, empty.schema(), &order_by)?;
+        assert_eq!(
+            frame.end_bound,
+            WindowFrameBound::Preceding(ScalarValue::Int64(Some(1)))
+        );
+        assert!(frame.is_causal());
+
+        // RANGE BETWEEN UNBOUNDED PRECEDING AND 1 FOLLOWING:
+        let frame = WindowFrame::new_bounds(
+            WindowFrameUnits::Range,
+            unbounded,
+            WindowFrameBound::Following(offset),
+        );
+        let frame = coerce_window_frame(frame, empty.schema(), &order_by)?;
+        assert!(!frame.is_causal());
+        Ok(())
+    }
+
     #[test]
     fn test_coerce_union() -> Result<()> {
         let left_plan = Arc::new(LogicalPlan::EmptyRelation(EmptyRelation {
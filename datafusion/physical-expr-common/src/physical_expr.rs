@@ -430,6 +430,22 @@ pub trait PhysicalExpr: Any + Send + Sync + Display + Debug + DynEq + DynHash {
     fn is_volatile_node(&self) -> bool {
         false
     }
+
+    /// Returns a relative measure of how expensive (or complex) this expression
+    /// is to evaluate, lower values meaning cheaper. Among expressions that are
+    /// known to be equivalent, cheaper ones are preferred as representatives;
+    /// e.g. when choosing the canonical expression of an equivalence class.
+    ///
+    /// Unlike [`Self::is_volatile_node`], this includes the cost of the inputs.
+    /// The default implementation counts this node as two, and adds the costs
+    /// of its children. Leaf expressions like columns and literals override
+    /// this to count as one, so a column is always cheaper than a function
+    /// applied to it. Expensive expressions may return a higher cost.
+    fn cost(&self) -> u64 {
+        self.children()
+            .into_iter()
+            .fold(2, |acc, child| acc.saturating_add(child.cost()))
+    }
 }
 
 #[deprecated(
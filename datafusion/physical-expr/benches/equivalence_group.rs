@@ -0,0 +1,128 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use datafusion_expr::Operator;
+use datafusion_physical_expr::equivalence::{
+    ConstExpr, ConstExprSet, EquivalenceClass, EquivalenceGroup, NormalizerCache,
+};
+use datafusion_physical_expr::expressions::{BinaryExpr, Column};
+use datafusion_physical_expr_common::physical_expr::PhysicalExpr;
+use std::hint::black_box;
+use std::sync::Arc;
+
+fn col(idx: usize) -> Arc<dyn PhysicalExpr> {
+    Arc::new(Column::new(&format!("c{idx}"), idx))
+}
+
+/// Creates `n` classes `[c0, c1], [c1, c2], ...` which all bridge into a
+/// single class.
+fn chained_classes(n: usize) -> Vec<EquivalenceClass> {
+    (0..n)
+        .map(|idx| EquivalenceClass::new([col(idx), col(idx + 1)]))
+        .collect()
+}
+
+/// Creates `n` classes `[c0, c1], [c2, c3], ...` which are all disjoint.
+fn disjoint_classes(n: usize) -> Vec<EquivalenceClass> {
+    (0..n)
+        .map(|idx| EquivalenceClass::new([col(2 * idx), col(2 * idx + 1)]))
+        .collect()
+}
+
+/// Creates `n` expressions `c1 + c3, c3 + c5, ...` cycling through `distinct`
+/// different expressions, mimicking a query referring to the same columns
+/// over and over.
+fn repeated_exprs(n: usize, distinct: usize) -> Vec<Arc<dyn PhysicalExpr>> {
+    (0..n)
+        .map(|idx| {
+            let idx = idx % distinct;
+            Arc::new(BinaryExpr::new(
+                col(2 * idx + 1),
+                Operator::Plus,
+                col(2 * idx + 3),
+            )) as _
+        })
+        .collect()
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("equivalence_group_bridge_classes");
+    for n in [100, 300, 500] {
+        let chained = chained_classes(n);
+        group.bench_with_input(BenchmarkId::new("chained", n), &chained, |b, classes| {
+            b.iter(|| black_box(EquivalenceGroup::new(classes.clone())))
+        });
+        let disjoint = disjoint_classes(n);
+        group.bench_with_input(
+            BenchmarkId::new("disjoint", n),
+            &disjoint,
+            |b, classes| b.iter(|| black_box(EquivalenceGroup::new(classes.clone()))),
+        );
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("equivalence_group_normalize_expr");
+    let eq_group = EquivalenceGroup::new(disjoint_classes(100));
+    let exprs = repeated_exprs(1000, 20);
+    group.bench_function("uncached", |b| {
+        b.iter(|| {
+            for expr in &exprs {
+                black_box(eq_group.normalize_expr(Arc::clone(expr)));
+            }
+        })
+    });
+    group.bench_function("cached", |b| {
+        b.iter(|| {
+            let mut cache = NormalizerCache::new(&eq_group);
+            for expr in &exprs {
+                black_box(cache.normalize_expr(Arc::clone(expr)));
+            }
+        })
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("const_expr_lookup");
+    let const_exprs = (0..1000)
+        .map(|idx| ConstExpr::from(col(idx)))
+        .collect::<Vec<_>>();
+    let set = ConstExprSet::new(&const_exprs);
+    // Look up every other constant, along with as many absent expressions:
+    let lookups = (0..1000).map(|idx| col(2 * idx)).collect::<Vec<_>>();
+    group.bench_function("linear_scan", |b| {
+        b.iter(|| {
+            for expr in &lookups {
+                black_box(
+                    const_exprs
+                        .iter()
+                        .any(|const_expr| const_expr.expr.eq(expr)),
+                );
+            }
+        })
+    });
+    group.bench_function("set", |b| {
+        b.iter(|| {
+            for expr in &lookups {
+                black_box(set.contains(expr));
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -15,20 +15,30 @@
 // specific language governing permissions and limitations
 // under the License.
 
+use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::fmt::Display;
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::ops::Deref;
 use std::sync::Arc;
 use std::vec::IntoIter;
 
 use super::ProjectionMapping;
-use crate::expressions::Literal;
+use crate::expressions::{BinaryExpr, Column, Literal};
 use crate::physical_expr::add_offset_to_expr;
 use crate::projection::ProjectionTargets;
-use crate::{PhysicalExpr, PhysicalExprRef, PhysicalSortExpr, PhysicalSortRequirement};
+use crate::utils::collect_columns;
+use crate::{
+    LexOrdering, PhysicalExpr, PhysicalExprRef, PhysicalSortExpr, PhysicalSortRequirement,
+};
 
-use datafusion_common::tree_node::{Transformed, TransformedResult, TreeNode};
-use datafusion_common::{JoinType, Result, ScalarValue};
-use datafusion_physical_expr_common::physical_expr::format_physical_expr_list;
+use arrow::datatypes::{DataType, Schema};
+use datafusion_common::tree_node::{Transformed, TreeNode};
+use datafusion_common::{JoinType, Result, ScalarValue, internal_err};
+use datafusion_expr::Operator;
+use datafusion_physical_expr_common::physical_expr::{
+    format_physical_expr_list, is_volatile,
+};
 
 use indexmap::{IndexMap, IndexSet};
 
@@ -38,7 +48,7 @@ use indexmap::{IndexMap, IndexSet};
 ///   different partitions.
 /// - `Uniform(Option<ScalarValue>)`: The constant expression has the same value
 ///   across all partitions, or is `None` if the value is unknown.
-#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, PartialEq, Hash)]
 pub enum AcrossPartitions {
     #[default]
     Heterogeneous,
@@ -60,6 +70,109 @@ impl Display for AcrossPartitions {
     }
 }
 
+/// Represents the values a constant expression takes across partitions. This
+/// is a refinement of [`AcrossPartitions`] that can also track the individual
+/// values of an expression that is constant within each partition. Has two
+/// variants:
+/// - `Uniform(Option<ScalarValue>)`: The constant expression has the same value
+///   across all partitions, or is `None` if the value is unknown.
+/// - `Heterogeneous(Vec<Option<ScalarValue>>)`: The constant expression may
+///   have different values for different partitions. If known, the vector
+///   holds the value of each partition (with `None` for unknown values);
+///   otherwise, it is empty.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum PartitionValues {
+    Uniform(Option<ScalarValue>),
+    Heterogeneous(Vec<Option<ScalarValue>>),
+}
+
+impl Default for PartitionValues {
+    fn default() -> Self {
+        Self::Heterogeneous(vec![])
+    }
+}
+
+impl PartitionValues {
+    /// Returns the value of each partition if these are known to differ
+    /// across partitions and are tracked individually (with `None` for
+    /// unknown values), and `None` otherwise.
+    pub fn per_partition_values(&self) -> Option<&[Option<ScalarValue>]> {
+        match self {
+            Self::Heterogeneous(values) if !values.is_empty() => Some(values),
+            _ => None,
+        }
+    }
+}
+
+impl Display for PartitionValues {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PartitionValues::Uniform(_) => {
+                write!(f, "{}", AcrossPartitions::from(self))
+            }
+            PartitionValues::Heterogeneous(values) if values.is_empty() => {
+                write!(f, "(heterogeneous)")
+            }
+            PartitionValues::Heterogeneous(values) => {
+                write!(f, "(heterogeneous: [")?;
+                for (idx, value) in values.iter().enumerate() {
+                    if idx > 0 {
+                        write!(f, ", ")?;
+                    }
+                    if let Some(val) = value {
+                        write!(f, "{val}")?;
+                    } else {
+                        write!(f, "unknown")?;
+                    }
+                }
+                write!(f, "])")
+            }
+        }
+    }
+}
+
+impl From<AcrossPartitions> for PartitionValues {
+    fn from(across: AcrossPartitions) -> Self {
+        match across {
+            AcrossPartitions::Heterogeneous => Self::default(),
+            AcrossPartitions::Uniform(value) => Self::Uniform(value),
+        }
+    }
+}
+
+impl From<&PartitionValues> for AcrossPartitions {
+    /// Discards per-partition values of heterogeneous constants.
+    fn from(values: &PartitionValues) -> Self {
+        match values {
+            PartitionValues::Heterogeneous(_) => Self::Heterogeneous,
+            PartitionValues::Uniform(value) => Self::Uniform(value.clone()),
+        }
+    }
+}
+
+impl From<PartitionValues> for AcrossPartitions {
+    /// Discards per-partition values of heterogeneous constants.
+    fn from(values: PartitionValues) -> Self {
+        match values {
+            PartitionValues::Heterogeneous(_) => Self::Heterogeneous,
+            PartitionValues::Uniform(value) => Self::Uniform(value),
+        }
+    }
+}
+
+/// Compares partition values with their coarser [`AcrossPartitions`] form,
+/// ignoring per-partition values of heterogeneous constants. This keeps
+/// comparisons written against the old `ConstExpr` API working.
+impl PartialEq<AcrossPartitions> for PartitionValues {
+    fn eq(&self, other: &AcrossPartitions) -> bool {
+        match (self, other) {
+            (Self::Heterogeneous(_), AcrossPartitions::Heterogeneous) => true,
+            (Self::Uniform(lhs), AcrossPartitions::Uniform(rhs)) => lhs == rhs,
+            _ => false,
+        }
+    }
+}
+
 /// A structure representing a expression known to be constant in a physical
 /// execution plan.
 ///
@@ -70,10 +183,11 @@ impl Display for AcrossPartitions {
 /// # Fields
 ///
 /// - `expr`: Constant expression for a node in the physical plan.
-/// - `across_partitions`: A boolean flag indicating whether the constant
-///   expression is the same across partitions. If set to `true`, the constant
-///   expression has same value for all partitions. If set to `false`, the
-///   constant expression may have different values for different partitions.
+/// - `partition_values`: The [`PartitionValues`] of the constant expression,
+///   indicating whether it has the same value for all partitions (and that
+///   value, if known), or may have different values for different partitions
+///   (and these values, if known). Use [`ConstExpr::partition_values`] to
+///   access it.
 ///
 /// # Example
 ///
@@ -89,29 +203,126 @@ pub struct ConstExpr {
     /// The expression that is known to be constant (e.g. a `Column`).
     pub expr: Arc<dyn PhysicalExpr>,
     /// Indicates whether the constant have the same value across all partitions.
-    pub across_partitions: AcrossPartitions,
+    partition_values: PartitionValues,
 }
 // TODO: The `ConstExpr` definition above can be in an inconsistent state where
-//       `expr` is a literal but `across_partitions` is not `Uniform`. Consider
+//       `expr` is a literal but `partition_values` is not `Uniform`. Consider
 //       a refactor to ensure that `ConstExpr` is always in a consistent state
 //       (either by changing type definition, or by API constraints).
 
 impl ConstExpr {
     /// Create a new constant expression from a physical expression, specifying
-    /// whether the constant expression is the same across partitions.
+    /// whether the constant expression is the same across partitions. Accepts
+    /// both [`PartitionValues`] and the coarser [`AcrossPartitions`].
     ///
     /// Note that you can also use `ConstExpr::from` to create a constant
     /// expression from just a physical expression, with the *safe* assumption
     /// of heterogenous values across partitions unless the expression is a
     /// literal.
-    pub fn new(expr: Arc<dyn PhysicalExpr>, across_partitions: AcrossPartitions) -> Self {
-        let mut result = ConstExpr::from(expr);
-        // Override the across partitions specification if the expression is not
-        // a literal.
-        if result.across_partitions == AcrossPartitions::Heterogeneous {
-            result.across_partitions = across_partitions;
+    pub fn new(
+        expr: Arc<dyn PhysicalExpr>,
+        across_partitions: impl Into<PartitionValues>,
+    ) -> Self {
+        ConstExpr::from(expr).with_across_partitions(across_partitions)
+    }
+
+    /// Creates a constant expression that is known to have the given value
+    /// across all partitions.
+    pub fn uniform(expr: Arc<dyn PhysicalExpr>, value: ScalarValue) -> Self {
+        Self::new(expr, PartitionValues::Uniform(Some(value)))
+    }
+
+    /// Creates a constant expression that is known to have the same value
+    /// across all partitions, where that value itself is unknown.
+    pub fn uniform_unknown(expr: Arc<dyn PhysicalExpr>) -> Self {
+        Self::new(expr, PartitionValues::Uniform(None))
+    }
+
+    /// Sets the values of this constant expression across partitions. The
+    /// given values are ignored if the expression is a literal, as literals
+    /// always have the same (known) value across all partitions.
+    pub fn with_across_partitions(
+        mut self,
+        across_partitions: impl Into<PartitionValues>,
+    ) -> Self {
+        if self.expr.as_any().downcast_ref::<Literal>().is_none() {
+            self.partition_values = across_partitions.into();
+        }
+        self
+    }
+
+    /// Returns the values of this constant expression across partitions.
+    pub fn partition_values(&self) -> &PartitionValues {
+        &self.partition_values
+    }
+
+    /// Returns whether this constant expression has the same value across all
+    /// partitions, discarding any per-partition values. This is the form that
+    /// equivalence classes track constants in.
+    #[deprecated(
+        since = "52.0.0",
+        note = "Use partition_values instead, and convert with AcrossPartitions::from if needed"
+    )]
+    pub fn across_partitions(&self) -> AcrossPartitions {
+        AcrossPartitions::from(&self.partition_values)
+    }
+
+    /// Returns whether this constant expression has the same value across all
+    /// partitions (whether or not that value is known).
+    pub fn is_uniform(&self) -> bool {
+        matches!(self.partition_values, PartitionValues::Uniform(_))
+    }
+
+    /// Returns whether this constant expression may have different values for
+    /// different partitions.
+    pub fn is_heterogeneous(&self) -> bool {
+        matches!(self.partition_values, PartitionValues::Heterogeneous(_))
+    }
+
+    /// Returns the value of this constant expression for each partition, if
+    /// it may differ across partitions and these values are tracked. See
+    /// [`PartitionValues::per_partition_values`].
+    pub fn per_partition_values(&self) -> Option<&[Option<ScalarValue>]> {
+        self.partition_values.per_partition_values()
+    }
+
+    /// Merges this constant expression with `other`, which asserts constancy
+    /// of the same expression elsewhere (e.g. in another branch of a plan).
+    /// Returns `None` if the underlying expressions differ. Otherwise, the
+    /// result is uniform only if both sides are uniform with the same known
+    /// value; in all other cases, it falls back to heterogeneous values.
+    pub fn try_merge(&self, other: &ConstExpr) -> Option<ConstExpr> {
+        if !self.expr.eq(&other.expr) {
+            return None;
+        }
+        let across_partitions = match (self.value(), other.value()) {
+            (Some(lhs), Some(rhs)) if lhs == rhs => self.partition_values.clone(),
+            _ => PartitionValues::default(),
+        };
+        Some(ConstExpr {
+            expr: Arc::clone(&self.expr),
+            partition_values: across_partitions,
+        })
+    }
+
+    /// Returns the value of this constant expression if it is known and the
+    /// same across all partitions, and `None` otherwise.
+    pub fn value(&self) -> Option<&ScalarValue> {
+        match &self.partition_values {
+            PartitionValues::Uniform(value) => value.as_ref(),
+            PartitionValues::Heterogeneous(_) => None,
+        }
+    }
+
+    /// Returns this constant expression with its underlying expression
+    /// normalized according to the given equivalence group (see
+    /// [`EquivalenceGroup::normalize_expr`]), keeping its partition values.
+    /// This way, constants asserted on equivalent expressions deduplicate.
+    pub fn normalize(&self, eq_group: &EquivalenceGroup) -> ConstExpr {
+        ConstExpr {
+            expr: eq_group.normalize_expr(Arc::clone(&self.expr)),
+            partition_values: self.partition_values.clone(),
         }
-        result
     }
 
     /// Returns a [`Display`]able list of `ConstExpr`.
@@ -135,16 +346,30 @@ impl ConstExpr {
     }
 }
 
+/// Compares the expressions and their full [`PartitionValues`]. Hence, a
+/// constant with unknown heterogeneous values (i.e. `Heterogeneous(vec![])`)
+/// is not equal to (and may hash differently from) the same constant with
+/// known per-partition values, even though both convert to the same
+/// [`AcrossPartitions::Heterogeneous`].
 impl PartialEq for ConstExpr {
     fn eq(&self, other: &Self) -> bool {
-        self.across_partitions == other.across_partitions && self.expr.eq(&other.expr)
+        self.partition_values == other.partition_values && self.expr.eq(&other.expr)
+    }
+}
+
+impl Eq for ConstExpr {}
+
+impl Hash for ConstExpr {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.expr.hash(state);
+        self.partition_values.hash(state);
     }
 }
 
 impl Display for ConstExpr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.expr)?;
-        write!(f, "{}", self.across_partitions)
+        write!(f, "{}", self.partition_values)
     }
 }
 
@@ -154,15 +379,63 @@ impl From<Arc<dyn PhysicalExpr>> for ConstExpr {
         // However, if we have a literal, it will have a single value that is the
         // same across all partitions.
         let across = if let Some(lit) = expr.as_any().downcast_ref::<Literal>() {
-            AcrossPartitions::Uniform(Some(lit.value().clone()))
+            PartitionValues::Uniform(Some(lit.value().clone()))
         } else {
-            AcrossPartitions::Heterogeneous
+            PartitionValues::default()
         };
         Self {
             expr,
-            across_partitions: across,
+            partition_values: across,
+        }
+    }
+}
+
+/// A set of expressions known to be constant, supporting constant-time
+/// membership checks. Checking membership by scanning a slice of
+/// [`ConstExpr`]s takes linear time, which adds up when there are many
+/// constants; this set relies on [`PhysicalExpr`] hashing instead.
+#[derive(Clone, Debug, Default)]
+pub struct ConstExprSet {
+    /// The constant expressions in this set.
+    exprs: IndexSet<Arc<dyn PhysicalExpr>>,
+}
+
+impl ConstExprSet {
+    /// Creates a set from the expressions of the given constants.
+    pub fn new<'a>(const_exprs: impl IntoIterator<Item = &'a ConstExpr>) -> Self {
+        Self {
+            exprs: const_exprs
+                .into_iter()
+                .map(|const_expr| Arc::clone(&const_expr.expr))
+                .collect(),
         }
     }
+
+    /// Adds the expression of the given constant to this set. Returns whether
+    /// the expression was newly inserted.
+    pub fn insert(&mut self, const_expr: &ConstExpr) -> bool {
+        self.exprs.insert(Arc::clone(&const_expr.expr))
+    }
+
+    /// Checks whether `expr` is among the constant expressions in this set.
+    pub fn contains(&self, expr: &Arc<dyn PhysicalExpr>) -> bool {
+        self.exprs.contains(expr)
+    }
+
+    /// Returns the number of expressions in this set.
+    pub fn len(&self) -> usize {
+        self.exprs.len()
+    }
+
+    /// Checks whether this set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.exprs.is_empty()
+    }
+
+    /// Returns an iterator over the expressions in this set.
+    pub fn iter(&self) -> impl Iterator<Item = &Arc<dyn PhysicalExpr>> {
+        self.exprs.iter()
+    }
 }
 
 /// An `EquivalenceClass` is a set of [`Arc<dyn PhysicalExpr>`]s that are known
@@ -172,7 +445,7 @@ impl From<Arc<dyn PhysicalExpr>> for ConstExpr {
 ///
 /// Two `EquivalenceClass`es are equal if they contains the same expressions in
 /// without any ordering.
-#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[derive(Clone, Debug, Default)]
 pub struct EquivalenceClass {
     /// The expressions in this equivalence class. The order doesn't matter for
     /// equivalence purposes.
@@ -180,22 +453,62 @@ pub struct EquivalenceClass {
     /// Indicates whether the expressions in this equivalence class have a
     /// constant value. A `Some` value indicates constant-ness.
     pub(crate) constant: Option<AcrossPartitions>,
+    /// Optional labels describing where the equalities in this class came
+    /// from (e.g. the predicates that introduced them). These are only for
+    /// diagnostic purposes, and do not take part in equality comparisons.
+    pub(crate) sources: Vec<Arc<str>>,
+    /// Index of the [canonical expression](Self::canonical_expr) in `exprs`,
+    /// kept up to date as members are added or removed. Meaningless if the
+    /// class is empty.
+    canonical: usize,
 }
 
 impl EquivalenceClass {
     // Create a new equivalence class from a pre-existing collection.
     pub fn new(exprs: impl IntoIterator<Item = Arc<dyn PhysicalExpr>>) -> Self {
-        let mut class = Self::default();
+        let exprs = exprs.into_iter();
+        let mut class = Self::with_capacity(exprs.size_hint().0);
         for expr in exprs {
             class.push(expr);
         }
         class
     }
 
-    /// Return the "canonical" expression for this class (the first element)
-    /// if non-empty.
+    /// Creates an empty equivalence class with room for at least `capacity`
+    /// expressions without reallocating.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            exprs: IndexSet::with_capacity(capacity),
+            ..Default::default()
+        }
+    }
+
+    /// Return the "canonical" expression for this class if non-empty. This is
+    /// the simplest expression according to [`compare_simplicity`]; e.g. a
+    /// `Column` is preferred over a `BinaryExpr`, and among columns, the one
+    /// with the lowest index is preferred. Remaining ties are broken in favor
+    /// of the earliest inserted expression.
+    ///
+    /// Volatile expressions (e.g. `random()`) are only chosen if there is no
+    /// deterministic member, as normalizing other expressions to them would
+    /// duplicate the volatile call.
+    ///
+    /// This is the expression that [`EquivalenceGroup::normalize_expr`]
+    /// replaces all members of this class with.
     pub fn canonical_expr(&self) -> Option<&Arc<dyn PhysicalExpr>> {
-        self.exprs.iter().next()
+        self.exprs.get_index(self.canonical)
+    }
+
+    /// Recomputes the index of the canonical expression from scratch. This is
+    /// necessary after members are removed or rewritten, as opposed to added.
+    pub(crate) fn update_canonical(&mut self) {
+        // Note that `min_by` returns the first of equally simple elements.
+        self.canonical = self
+            .exprs
+            .iter()
+            .enumerate()
+            .min_by(|(_, left), (_, right)| compare_simplicity(left, right))
+            .map_or(0, |(idx, _)| idx);
     }
 
     /// Insert the expression into this class, meaning it is known to be equal to
@@ -212,12 +525,67 @@ impl EquivalenceClass {
                 self.constant = Some(expr_across);
             }
         }
-        self.exprs.insert(expr);
+        let (idx, inserted) = self.exprs.insert_full(expr);
+        // A new member only becomes canonical if it is strictly simpler, so
+        // that ties are broken in favor of the earliest inserted expression:
+        if inserted
+            && (idx == 0
+                || compare_simplicity(&self.exprs[idx], &self.exprs[self.canonical])
+                    == Ordering::Less)
+        {
+            self.canonical = idx;
+        }
+    }
+
+    /// Removes the given expression from this class. Returns whether the
+    /// expression was a member of this class.
+    ///
+    /// Note that removing members may leave a single expression behind, which
+    /// makes the class [trivial](Self::is_trivial). Callers may want to prune
+    /// such classes.
+    pub fn remove(&mut self, expr: &Arc<dyn PhysicalExpr>) -> bool {
+        // Preserve the insertion order of the remaining members:
+        let removed = self.exprs.shift_remove(expr);
+        if removed {
+            self.update_canonical();
+        }
+        removed
+    }
+
+    /// Removes members that are equal to an earlier member of this class.
+    /// Members are normally deduplicated upon insertion via hashing, so this
+    /// is only a safety net against expression types whose `Hash` and `Eq`
+    /// implementations are inconsistent. Note that this is a quadratic scan.
+    pub fn dedup_structurally(&mut self) {
+        let mut idx = 1;
+        while idx < self.exprs.len() {
+            let expr = &self.exprs[idx];
+            if self.exprs.iter().take(idx).any(|prev| prev.eq(expr)) {
+                self.exprs.shift_remove_index(idx);
+            } else {
+                idx += 1;
+            }
+        }
+        self.update_canonical();
+    }
+
+    /// Inserts all the given expressions into this class like [`Self::push`],
+    /// skipping expressions that are already members.
+    pub fn extend_from_iter(
+        &mut self,
+        exprs: impl IntoIterator<Item = Arc<dyn PhysicalExpr>>,
+    ) {
+        for expr in exprs {
+            self.push(expr);
+        }
     }
 
     /// Inserts all the expressions from other into this class.
     pub fn extend(&mut self, other: Self) {
-        self.exprs.extend(other.exprs);
+        self.extend_from_iter(other.exprs);
+        for source in other.sources {
+            self.add_source(source);
+        }
         match (&self.constant, &other.constant) {
             (Some(across), Some(_)) => {
                 // TODO: Return an error if constant values do not agree.
@@ -230,10 +598,68 @@ impl EquivalenceClass {
         }
     }
 
+    /// Returns whether (and how) the expressions in this class are constant,
+    /// or `None` if they are not known to be constant.
+    pub fn constant(&self) -> Option<&AcrossPartitions> {
+        self.constant.as_ref()
+    }
+
+    /// Marks the expressions in this class as constant with the given values
+    /// across partitions.
+    pub fn with_constant(mut self, constant: AcrossPartitions) -> Self {
+        self.constant = Some(constant);
+        self
+    }
+
+    /// Returns the labels describing where the equalities in this class came
+    /// from, see [`EquivalenceGroup::add_equal_conditions_tagged`].
+    pub fn sources(&self) -> &[Arc<str>] {
+        &self.sources
+    }
+
+    /// Records `source` as one of the origins of this class, unless it is
+    /// already recorded.
+    fn add_source(&mut self, source: Arc<str>) {
+        if !self.sources.contains(&source) {
+            self.sources.push(source);
+        }
+    }
+
     /// Returns whether this equivalence class has any entries in common with
     /// `other`.
     pub fn contains_any(&self, other: &Self) -> bool {
-        self.exprs.intersection(&other.exprs).next().is_some()
+        self.common_expr(other).is_some()
+    }
+
+    /// Returns the first expression of this class (in insertion order) that is
+    /// also in `other`, or `None` if the classes are disjoint. This is useful
+    /// to explain why two classes were merged.
+    pub fn common_expr<'a>(
+        &'a self,
+        other: &EquivalenceClass,
+    ) -> Option<&'a Arc<dyn PhysicalExpr>> {
+        self.exprs.iter().find(|expr| other.exprs.contains(*expr))
+    }
+
+    /// Returns a new equivalence class with the expressions that are in both
+    /// this class and `other`, in the insertion order of this class.
+    pub fn intersection(&self, other: &EquivalenceClass) -> EquivalenceClass {
+        Self::new(self.exprs.intersection(&other.exprs).cloned())
+    }
+
+    /// Returns a new equivalence class with the expressions that are in this
+    /// class but not in `other`, in the insertion order of this class.
+    pub fn difference(&self, other: &EquivalenceClass) -> EquivalenceClass {
+        Self::new(self.exprs.difference(&other.exprs).cloned())
+    }
+
+    /// Returns the members of this class sorted by their display strings.
+    /// Unlike iteration order, which follows insertion order, this is the same
+    /// for all classes with the same members.
+    pub fn sorted_exprs(&self) -> Vec<Arc<dyn PhysicalExpr>> {
+        let mut exprs = self.exprs.iter().cloned().collect::<Vec<_>>();
+        exprs.sort_by_cached_key(|expr| expr.to_string());
+        exprs
     }
 
     /// Returns whether this equivalence class is trivial, meaning that it is
@@ -245,8 +671,13 @@ impl EquivalenceClass {
 
     /// Adds the given offset to all columns in the expressions inside this
     /// class. This is used when schemas are appended, e.g. in joins.
+    ///
+    /// Members keep their insertion order, so the [canonical expression] of
+    /// the result is the offset version of the canonical expression of `self`.
+    ///
+    /// [canonical expression]: Self::canonical_expr
     pub fn try_with_offset(&self, offset: isize) -> Result<Self> {
-        let mut cls = Self::default();
+        let mut cls = Self::with_capacity(self.len());
         for expr_result in self
             .exprs
             .iter()
@@ -255,8 +686,91 @@ impl EquivalenceClass {
         {
             cls.push(expr_result?);
         }
+        cls.sources.clone_from(&self.sources);
         Ok(cls)
     }
+
+    /// Returns a new equivalence class whose members are the results of
+    /// applying `f` to the members of this class, without duplicates. The
+    /// constant-ness (if `f` does not produce a literal) and sources of this
+    /// class are kept.
+    pub fn map<F>(&self, f: F) -> EquivalenceClass
+    where
+        F: Fn(&Arc<dyn PhysicalExpr>) -> Arc<dyn PhysicalExpr>,
+    {
+        let mut mapped = Self::new(self.exprs.iter().map(f));
+        if mapped.constant.is_none() {
+            mapped.constant.clone_from(&self.constant);
+        }
+        mapped.sources.clone_from(&self.sources);
+        mapped
+    }
+
+    /// Subtracts the given offset from all columns in the expressions inside
+    /// this class, e.g. when splitting a joined schema back into its inputs.
+    /// Returns `None` if any column index is below `offset`.
+    pub fn try_with_offset_sub(&self, offset: usize) -> Option<Self> {
+        let offset = isize::try_from(offset).ok()?;
+        self.try_with_offset(-offset).ok()
+    }
+}
+
+/// Returns the largest column index referenced by the given expressions, or
+/// `None` if they do not reference any columns.
+fn max_column_index<'a>(
+    exprs: impl IntoIterator<Item = &'a Arc<dyn PhysicalExpr>>,
+) -> Option<usize> {
+    exprs
+        .into_iter()
+        .flat_map(collect_columns)
+        .map(|col| col.index())
+        .max()
+}
+
+/// Compares the given expressions by "simplicity", which determines canonical
+/// representatives of equivalence classes. Deterministic expressions are
+/// simpler than volatile ones. Otherwise, cheaper expressions according to
+/// [`PhysicalExpr::cost`] are simpler; among columns of equal cost, the one with the
+/// lower index is simpler. Other expressions of equal cost compare as equal.
+fn compare_simplicity(
+    left: &Arc<dyn PhysicalExpr>,
+    right: &Arc<dyn PhysicalExpr>,
+) -> Ordering {
+    let volatility = is_volatile(left).cmp(&is_volatile(right));
+    volatility
+        .then_with(|| left.cost().cmp(&right.cost()))
+        .then_with(|| {
+            let left = left.as_any().downcast_ref::<Column>();
+            let right = right.as_any().downcast_ref::<Column>();
+            match (left, right) {
+                (Some(left), Some(right)) => left.index().cmp(&right.index()),
+                _ => Ordering::Equal,
+            }
+        })
+}
+
+impl PartialEq for EquivalenceClass {
+    fn eq(&self, other: &Self) -> bool {
+        // Sources are diagnostic only, and do not affect equality:
+        self.exprs == other.exprs && self.constant == other.constant
+    }
+}
+
+impl Eq for EquivalenceClass {}
+
+impl Hash for EquivalenceClass {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        // Equality does not depend on the order of expressions, so combine
+        // their hashes in an order-independent way:
+        let exprs_hash = self.exprs.iter().fold(0u64, |acc, expr| {
+            let mut hasher = DefaultHasher::new();
+            expr.hash(&mut hasher);
+            acc.wrapping_add(hasher.finish())
+        });
+        state.write_usize(self.exprs.len());
+        state.write_u64(exprs_hash);
+        self.constant.hash(state);
+    }
 }
 
 impl Deref for EquivalenceClass {
@@ -276,13 +790,26 @@ impl IntoIterator for EquivalenceClass {
     }
 }
 
+impl FromIterator<Arc<dyn PhysicalExpr>> for EquivalenceClass {
+    fn from_iter<T: IntoIterator<Item = Arc<dyn PhysicalExpr>>>(iter: T) -> Self {
+        Self::new(iter)
+    }
+}
+
 impl Display for EquivalenceClass {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        // Print members in a canonical order, so that the output does not
+        // depend on the order they were inserted in:
+        let members = self.sorted_exprs();
         write!(f, "{{")?;
-        write!(f, "members: {}", format_physical_expr_list(&self.exprs))?;
+        write!(f, "members: {}", format_physical_expr_list(&members))?;
         if let Some(across) = &self.constant {
             write!(f, ", constant: {across}")?;
         }
+        // The alternate form (`{:#}`) also shows where equalities came from:
+        if f.alternate() && !self.sources.is_empty() {
+            write!(f, ", sources: [{}]", self.sources.join(", "))?;
+        }
         write!(f, "}}")
     }
 }
@@ -314,30 +841,105 @@ impl EquivalenceGroup {
         classes.into_iter().collect::<Vec<_>>().into()
     }
 
+    /// Creates an equivalence group from the given equalities. This results in
+    /// the same equivalence classes as calling [`Self::add_equal_conditions`]
+    /// for each pair on an empty group, but classes are bridged in a single
+    /// pass. Trivial equalities (e.g. `a = a`) do not create classes.
+    pub fn from_equalities(
+        pairs: impl IntoIterator<Item = (Arc<dyn PhysicalExpr>, Arc<dyn PhysicalExpr>)>,
+    ) -> Self {
+        Self::new(
+            pairs
+                .into_iter()
+                .filter(|(left, right)| !left.eq(right))
+                .map(|(left, right)| EquivalenceClass::new([left, right])),
+        )
+    }
+
+    /// Creates an equivalence group from the given equivalence classes like
+    /// [`Self::new`], and additionally returns whether the classes had to be
+    /// simplified in the process; i.e. whether overlapping classes were merged
+    /// or trivial classes were removed. A `true` value indicates that the input
+    /// was not already in its minimal form.
+    pub fn new_checked(
+        classes: impl IntoIterator<Item = EquivalenceClass>,
+    ) -> (Self, bool) {
+        let classes = classes.into_iter().collect::<Vec<_>>();
+        let mut result = Self {
+            map: classes
+                .iter()
+                .enumerate()
+                .flat_map(|(idx, cls)| {
+                    cls.iter().map(move |expr| (Arc::clone(expr), idx))
+                })
+                .collect(),
+            classes,
+        };
+        let changed = result.remove_redundant_entries();
+        (result, changed)
+    }
+
+    /// Removes all equivalence classes from this group, keeping the allocated
+    /// capacity for reuse.
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.classes.clear();
+    }
+
+    /// Returns the total number of expressions across all equivalence classes
+    /// in this group.
+    pub fn total_exprs(&self) -> usize {
+        self.classes.iter().map(|cls| cls.len()).sum()
+    }
+
+    /// Renders this group as a string that does not depend on the order in
+    /// which classes and their members were inserted, unlike [`Display`]
+    /// (which only orders members within classes). Classes are rendered like
+    /// their `Display` form and sorted, which makes the result suitable for
+    /// golden tests.
+    pub fn canonical_form(&self) -> String {
+        let mut classes = self.iter().map(|cls| cls.to_string()).collect::<Vec<_>>();
+        classes.sort_unstable();
+        format!("[{}]", classes.join(", "))
+    }
+
+    /// Returns an iterator over all expressions in this group, each paired
+    /// with the index of the equivalence class it belongs to.
+    pub fn expr_class_pairs(
+        &self,
+    ) -> impl Iterator<Item = (&Arc<dyn PhysicalExpr>, usize)> {
+        self.classes
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, cls)| cls.iter().map(move |expr| (expr, idx)))
+    }
+
     /// Adds `expr` as a constant expression to this equivalence group.
     pub fn add_constant(&mut self, const_expr: ConstExpr) {
         // If the expression is already in an equivalence class, we should
         // adjust the constant-ness of the class if necessary:
+        // Classes do not track per-partition values of constants:
+        let const_across = AcrossPartitions::from(const_expr.partition_values());
         if let Some(idx) = self.map.get(&const_expr.expr) {
             let cls = &mut self.classes[*idx];
             if let Some(across) = cls.constant.as_mut() {
                 // TODO: Return an error if constant values do not agree.
                 if *across == AcrossPartitions::Heterogeneous {
-                    *across = const_expr.across_partitions;
+                    *across = const_across;
                 }
             } else {
-                cls.constant = Some(const_expr.across_partitions);
+                cls.constant = Some(const_across);
             }
             return;
         }
         // If the expression is not in any equivalence class, but has the same
         // constant value with some class, add it to that class:
-        if let AcrossPartitions::Uniform(_) = &const_expr.across_partitions {
+        if let AcrossPartitions::Uniform(_) = &const_across {
             for (idx, cls) in self.classes.iter_mut().enumerate() {
                 if cls
                     .constant
                     .as_ref()
-                    .is_some_and(|across| const_expr.across_partitions.eq(across))
+                    .is_some_and(|across| const_across.eq(across))
                 {
                     self.map.insert(Arc::clone(&const_expr.expr), idx);
                     cls.push(const_expr.expr);
@@ -348,7 +950,7 @@ impl EquivalenceGroup {
         // Otherwise, create a new class with the expression as the only member:
         let mut new_class = EquivalenceClass::new(std::iter::once(const_expr.expr));
         if new_class.constant.is_none() {
-            new_class.constant = Some(const_expr.across_partitions);
+            new_class.constant = Some(const_across);
         }
         Self::update_lookup_table(&mut self.map, &new_class, self.classes.len());
         self.classes.push(new_class);
@@ -376,6 +978,28 @@ impl EquivalenceGroup {
         change
     }
 
+    /// Returns the given constants along with all expressions that are known
+    /// to be constant through this group; i.e. every member of a class that
+    /// contains one of the given constants. Derived constants carry the same
+    /// values across partitions as the constant they are derived from.
+    pub fn constant_closure(&self, consts: &[ConstExpr]) -> Vec<ConstExpr> {
+        let mut result = consts.to_vec();
+        let mut seen = ConstExprSet::new(consts);
+        for const_expr in consts {
+            let Some(cls) = self.equivalence_class(&const_expr.expr) else {
+                continue;
+            };
+            for expr in cls.iter() {
+                let derived =
+                    ConstExpr::new(Arc::clone(expr), const_expr.partition_values.clone());
+                if seen.insert(&derived) {
+                    result.push(derived);
+                }
+            }
+        }
+        result
+    }
+
     /// Adds the equality `left` = `right` to this equivalence group. New
     /// equality conditions often arise after steps like `Filter(a = b)`,
     /// `Alias(a, a as b)` etc. Returns whether the given equality defines
@@ -385,6 +1009,10 @@ impl EquivalenceGroup {
         left: Arc<dyn PhysicalExpr>,
         right: Arc<dyn PhysicalExpr>,
     ) -> bool {
+        // An expression is trivially equal to itself:
+        if left.eq(&right) {
+            return false;
+        }
         let first_class = self.map.get(&left).copied();
         let second_class = self.map.get(&right).copied();
         match (first_class, second_class) {
@@ -393,9 +1021,9 @@ impl EquivalenceGroup {
                 // we should unify/bridge these classes.
                 match first_idx.cmp(&second_idx) {
                     // The equality is already known, return and signal this:
-                    std::cmp::Ordering::Equal => return false,
+                    Ordering::Equal => return false,
                     // Swap indices to ensure `first_idx` is the lesser index.
-                    std::cmp::Ordering::Greater => {
+                    Ordering::Greater => {
                         std::mem::swap(&mut first_idx, &mut second_idx);
                     }
                     _ => {}
@@ -430,6 +1058,103 @@ impl EquivalenceGroup {
         false
     }
 
+    /// Adds the equality `left` = `right` to this equivalence group like
+    /// [`Self::add_equal_conditions`], but returns whether the group changed
+    /// as a result; i.e. whether a class was created, extended or merged with
+    /// another. The group stays the same if both expressions are equal, or
+    /// already belong to the same class. This is useful for fixpoint iterations.
+    pub fn add_equal_conditions_checked(
+        &mut self,
+        left: Arc<dyn PhysicalExpr>,
+        right: Arc<dyn PhysicalExpr>,
+    ) -> bool {
+        let unchanged = left.eq(&right)
+            || match (self.map.get(&left), self.map.get(&right)) {
+                (Some(first_idx), Some(second_idx)) => first_idx == second_idx,
+                _ => false,
+            };
+        self.add_equal_conditions(left, right);
+        !unchanged
+    }
+
+    /// Adds all the given equalities to this equivalence group. This results
+    /// in the same equivalence classes as calling [`Self::add_equal_conditions`]
+    /// for each pair in sequence, but classes are bridged in a single pass at
+    /// the end, which is cheaper for many equalities (e.g. composite join keys).
+    pub fn add_equal_conditions_batch(
+        &mut self,
+        pairs: &[(PhysicalExprRef, PhysicalExprRef)],
+    ) {
+        for (left, right) in pairs.iter().filter(|(left, right)| !left.eq(right)) {
+            let class = EquivalenceClass::new([Arc::clone(left), Arc::clone(right)]);
+            Self::update_lookup_table(&mut self.map, &class, self.classes.len());
+            self.classes.push(class);
+        }
+        self.bridge_classes();
+    }
+
+    /// Adds the equality `left` = `right` to this equivalence group like
+    /// [`Self::add_equal_conditions`], and additionally records `source` (e.g.
+    /// the predicate introducing the equality) on the resulting class. The
+    /// label can be a static string or one formatted at runtime, such as the
+    /// text of the predicate. Use [`EquivalenceClass::sources`] to retrieve
+    /// these labels.
+    pub fn add_equal_conditions_tagged(
+        &mut self,
+        left: Arc<dyn PhysicalExpr>,
+        right: Arc<dyn PhysicalExpr>,
+        source: impl Into<Arc<str>>,
+    ) -> bool {
+        let key = Arc::clone(&left);
+        let result = self.add_equal_conditions(left, right);
+        if let Some(idx) = self.map.get(&key) {
+            self.classes[*idx].add_source(source.into());
+        }
+        result
+    }
+
+    /// Removes the given expression from the equivalence class it belongs to
+    /// (if any), e.g. after it is projected away. Classes that become trivial
+    /// as a result are removed from the group.
+    pub fn remove_expr(&mut self, expr: &Arc<dyn PhysicalExpr>) {
+        if let Some(idx) = self.map.swap_remove(expr) {
+            self.classes[idx].remove(expr);
+            self.remove_redundant_entries();
+        }
+    }
+
+    /// Removes the equivalence class at the given index from this group and
+    /// returns it, or returns `None` if the index is out of bounds. The last
+    /// class takes the place of the removed one, so the indices of other
+    /// classes may change.
+    ///
+    /// Removing a class leaves the remaining classes disjoint and non-trivial,
+    /// and mutating methods like [`Self::add_equal_conditions`] re-establish
+    /// this form on their own, so no further cleanup is needed afterwards.
+    pub fn remove_class(&mut self, index: usize) -> Option<EquivalenceClass> {
+        (index < self.classes.len()).then(|| self.remove_class_at_idx(index))
+    }
+
+    /// Retains only the equivalence classes for which `predicate` returns
+    /// `true`, e.g. to prune classes whose expressions are no longer valid
+    /// for a schema. Remaining classes keep their relative order.
+    pub fn retain_classes<F>(&mut self, predicate: F)
+    where
+        F: FnMut(&EquivalenceClass) -> bool,
+    {
+        let len = self.classes.len();
+        self.classes.retain(predicate);
+        if self.classes.len() != len {
+            self.map.clear();
+            for (idx, cls) in self.classes.iter().enumerate() {
+                Self::update_lookup_table(&mut self.map, cls, idx);
+            }
+        }
+        // Removing classes cannot make the remaining ones overlap, but bridge
+        // them anyway to be on the safe side:
+        self.bridge_classes();
+    }
+
     /// Removes the equivalence class at the given index from this group.
     fn remove_class_at_idx(&mut self, idx: usize) -> EquivalenceClass {
         // Remove the class at the given index:
@@ -468,8 +1193,41 @@ impl EquivalenceGroup {
                 change = true;
             }
         }
+        if change {
+            // Removed classes may have shared expressions with other classes,
+            // whose lookup entries are gone now:
+            self.map.clear();
+            for (idx, cls) in self.classes.iter().enumerate() {
+                Self::update_lookup_table(&mut self.map, cls, idx);
+            }
+        }
         // Then, unify/bridge groups that have common expressions:
-        self.bridge_classes() || change
+        let change = self.bridge_classes() || change;
+        self.check_invariants();
+        change
+    }
+
+    /// Checks that this group contains no [trivial] classes, and that the
+    /// lookup table maps every expression to its class, as many methods rely
+    /// on these invariants. Note that classes with a single constant
+    /// expression are not trivial. This check is compiled out in release
+    /// builds.
+    ///
+    /// [trivial]: EquivalenceClass::is_trivial
+    fn check_invariants(&self) {
+        #[cfg(debug_assertions)]
+        for cls in self.classes.iter() {
+            assert!(
+                !cls.is_trivial(),
+                "Trivial equivalence class in group: {cls}"
+            );
+        }
+        #[cfg(debug_assertions)]
+        for (idx, cls) in self.classes.iter().enumerate() {
+            for expr in cls.iter() {
+                assert_eq!(self.map.get(expr), Some(&idx), "Stale lookup entry: {expr}");
+            }
+        }
     }
 
     /// This utility function unifies/bridges classes that have common expressions.
@@ -477,49 +1235,130 @@ impl EquivalenceGroup {
     /// Since both classes contain `b`, columns `a`, `b` and `c` are actually all
     /// equal and belong to one class. This utility converts merges such classes.
     /// Returns whether any change was made to the equivalence group.
+    ///
+    /// Classes sharing expressions are found via a union-find structure over
+    /// class indices, so this runs in near-linear time in the total number of
+    /// expressions. Merged classes take the position of their first member
+    /// class, and the lookup table is rebuilt if any merge happens.
     fn bridge_classes(&mut self) -> bool {
-        let (mut idx, mut change) = (0, false);
-        'scan: while idx < self.classes.len() {
-            for other_idx in (idx + 1..self.classes.len()).rev() {
-                if self.classes[idx].contains_any(&self.classes[other_idx]) {
-                    let extension = self.remove_class_at_idx(other_idx);
-                    Self::update_lookup_table(&mut self.map, &extension, idx);
-                    self.classes[idx].extend(extension);
+        /// Finds the representative of the set containing `idx`, compressing
+        /// paths along the way.
+        fn find(parents: &mut [usize], mut idx: usize) -> usize {
+            while parents[idx] != idx {
+                parents[idx] = parents[parents[idx]];
+                idx = parents[idx];
+            }
+            idx
+        }
+
+        let mut parents = (0..self.classes.len()).collect::<Vec<_>>();
+        let mut change = false;
+        let mut owners = HashMap::<&Arc<dyn PhysicalExpr>, usize>::new();
+        for (idx, cls) in self.classes.iter().enumerate() {
+            for expr in cls.iter() {
+                let owner = *owners.entry(expr).or_insert(idx);
+                let (first, second) =
+                    (find(&mut parents, owner), find(&mut parents, idx));
+                if first != second {
+                    // Always keep the smaller index as the representative so
+                    // that merged classes retain their earliest position:
+                    parents[first.max(second)] = first.min(second);
                     change = true;
-                    continue 'scan;
                 }
             }
-            idx += 1;
         }
-        change
-    }
+        if !change {
+            self.check_invariants();
+            return false;
+        }
 
-    /// Extends this equivalence group with the `other` equivalence group.
+        // Merge every class into the class representing its set. Since
+        // representatives have the smallest index in their sets, they are
+        // always visited before the classes merged into them.
+        let classes = std::mem::take(&mut self.classes);
+        let mut positions = vec![0; classes.len()];
+        for (idx, cls) in classes.into_iter().enumerate() {
+            let root = find(&mut parents, idx);
+            if root == idx {
+                positions[idx] = self.classes.len();
+                self.classes.push(cls);
+            } else {
+                self.classes[positions[root]].extend(cls);
+            }
+        }
+        self.map.clear();
+        for (idx, cls) in self.classes.iter().enumerate() {
+            Self::update_lookup_table(&mut self.map, cls, idx);
+        }
+        self.check_invariants();
+        true
+    }
+
+    /// Extends this equivalence group with the `other` equivalence group.
     /// Returns whether any equivalence classes were unified/bridged as a
     /// result of the extension process.
     pub fn extend(&mut self, other: Self) -> bool {
+        let offset = self.classes.len();
         for (idx, cls) in other.classes.iter().enumerate() {
             // Update the lookup table for the new class:
-            Self::update_lookup_table(&mut self.map, cls, idx);
+            Self::update_lookup_table(&mut self.map, cls, offset + idx);
         }
         self.classes.extend(other.classes);
         self.bridge_classes()
     }
 
     /// Normalizes the given physical expression according to this group. The
-    /// expression is replaced with the first (canonical) expression in the
-    /// equivalence class it matches with (if any).
+    /// expression is replaced with the canonical expression of the equivalence
+    /// class it matches with (if any).
     pub fn normalize_expr(&self, expr: Arc<dyn PhysicalExpr>) -> Arc<dyn PhysicalExpr> {
-        expr.transform(|expr| {
-            let cls = self.get_equivalence_class(&expr);
-            let Some(canonical) = cls.and_then(|cls| cls.canonical_expr()) else {
-                return Ok(Transformed::no(expr));
-            };
-            Ok(Transformed::yes(Arc::clone(canonical)))
-        })
-        .data()
-        .unwrap()
+        self.normalize_expr_ref(&expr).unwrap_or(expr)
+    }
+
+    /// Normalizes the given physical expression according to this group, see
+    /// [`Self::normalize_expr`]. Returns `None` if normalization leaves the
+    /// expression unchanged, so that callers holding a reference can avoid
+    /// allocating a new one.
+    pub fn normalize_expr_ref(
+        &self,
+        expr: &Arc<dyn PhysicalExpr>,
+    ) -> Option<Arc<dyn PhysicalExpr>> {
+        let normalized = Arc::clone(expr)
+            .transform(|expr| {
+                let cls = self.equivalence_class(&expr);
+                match cls.and_then(|cls| cls.canonical_expr()) {
+                    Some(canonical) if !canonical.eq(&expr) => {
+                        Ok(Transformed::yes(Arc::clone(canonical)))
+                    }
+                    _ => Ok(Transformed::no(expr)),
+                }
+            })
+            .unwrap();
         // The unwrap above is safe because the closure always returns `Ok`.
+        normalized.transformed.then_some(normalized.data)
+    }
+
+    /// Normalizes the given physical expressions according to this group, see
+    /// [`Self::normalize_expr`]. Returns `None` if every expression is left
+    /// unchanged, so that callers can keep using the originals instead of
+    /// allocating a new vector.
+    pub fn normalize_exprs_if_changed(
+        &self,
+        exprs: &[Arc<dyn PhysicalExpr>],
+    ) -> Option<Vec<Arc<dyn PhysicalExpr>>> {
+        // Only allocate once the first expression actually changes:
+        let (idx, normalized) = exprs
+            .iter()
+            .enumerate()
+            .find_map(|(idx, expr)| Some((idx, self.normalize_expr_ref(expr)?)))?;
+        let mut result = Vec::with_capacity(exprs.len());
+        result.extend(exprs[..idx].iter().cloned());
+        result.push(normalized);
+        result.extend(
+            exprs[idx + 1..]
+                .iter()
+                .map(|expr| self.normalize_expr(Arc::clone(expr))),
+        );
+        Some(result)
     }
 
     /// Normalizes the given sort expression according to this group. The
@@ -584,6 +1423,48 @@ impl EquivalenceGroup {
             .filter(|req| self.is_expr_constant(&req.expr).is_none())
     }
 
+    /// Checks whether the `available` ordering satisfies the `required`
+    /// ordering according to this group. This is the case when the normalized
+    /// `required` ordering is a prefix of the normalized `available` ordering
+    /// with the same sort options. For example, if `x` and `a` are known to be
+    /// equal, `[x ASC, b ASC]` satisfies `[a ASC]`.
+    ///
+    /// Note that this only consults equivalences and constants in this group;
+    /// see [`EquivalenceProperties::ordering_satisfy`] for a more complete
+    /// check that also considers other known orderings.
+    ///
+    /// [`EquivalenceProperties::ordering_satisfy`]: crate::EquivalenceProperties::ordering_satisfy
+    pub fn ordering_satisfies(
+        &self,
+        available: &LexOrdering,
+        required: &LexOrdering,
+    ) -> bool {
+        let normalize = |ordering: &LexOrdering| {
+            LexOrdering::new(self.normalize_sort_exprs(ordering.iter().cloned()))
+        };
+        let Some(required) = normalize(required) else {
+            // All required expressions are constant:
+            return true;
+        };
+        let Some(available) = normalize(available) else {
+            return false;
+        };
+        required.len() <= available.len()
+            && required.iter().zip(available.iter()).all(|(r, a)| r.eq(a))
+    }
+
+    /// Checks whether orderings `a` and `b` are equivalent according to this
+    /// group, i.e. whether they are the same after normalization (see
+    /// [`Self::normalize_sort_exprs`]). For example, if `a` and `c` are known
+    /// to be equal, `[a ASC]` and `[c ASC]` are equivalent. Orderings that
+    /// consist only of constants are equivalent to each other.
+    pub fn orderings_equivalent(&self, a: &LexOrdering, b: &LexOrdering) -> bool {
+        let normalize = |ordering: &LexOrdering| {
+            LexOrdering::new(self.normalize_sort_exprs(ordering.iter().cloned()))
+        };
+        normalize(a) == normalize(b)
+    }
+
     /// Perform an indirect projection of `expr` by consulting the equivalence
     /// classes.
     fn project_expr_indirect(
@@ -607,7 +1488,8 @@ impl EquivalenceGroup {
                 return Some(Arc::clone(target));
             }
         }
-        // Project a non-leaf expression by projecting its children.
+        // Project a non-leaf expression (e.g. a `CASE` expression, including
+        // all its branches) by projecting its children.
         let children = expr.children();
         if children.is_empty() {
             // A leaf expression should be inside the mapping.
@@ -627,7 +1509,9 @@ impl EquivalenceGroup {
                 }
             })
             .collect::<Option<Vec<_>>>()
-            .map(|children| Arc::clone(expr).with_new_children(children).unwrap())
+            // The expression may reject its projected children, in which case
+            // it cannot be projected:
+            .and_then(|children| Arc::clone(expr).with_new_children(children).ok())
     }
 
     fn augment_projection_mapping<'a>(
@@ -637,7 +1521,7 @@ impl EquivalenceGroup {
         mapping
             .iter()
             .map(|(k, v)| {
-                let eq_class = self.get_equivalence_class(k);
+                let eq_class = self.equivalence_class(k);
                 (k, (v, eq_class))
             })
             .collect()
@@ -645,6 +1529,15 @@ impl EquivalenceGroup {
 
     /// Projects `expr` according to the given projection mapping.
     /// If the resulting expression is invalid after projection, returns `None`.
+    /// Non-leaf expressions are projected by projecting all their children,
+    /// so `None` is returned if any child cannot be projected.
+    ///
+    /// A direct match takes precedence over an equivalence match: If `expr` is
+    /// itself a source in the mapping, it projects to its own (first) target.
+    /// Otherwise, `expr` projects to the target of a source it is equivalent
+    /// to. For example, given the mapping `(a as a1)` and the equivalence
+    /// `a == b`, both `a` and `b` project to `a1`; but given the mapping
+    /// `(a as a1, b as b1)`, `b` projects to `b1`.
     pub fn project_expr(
         &self,
         mapping: &ProjectionMapping,
@@ -660,6 +1553,28 @@ impl EquivalenceGroup {
         }
     }
 
+    /// Returns all targets that `expr` projects to according to the given
+    /// projection mapping. Unlike [`Self::project_expr`], which returns only
+    /// the first target, this collects the targets of every source that is
+    /// equal to `expr`, either directly or through equivalence classes. For
+    /// example, given the mapping `(a, a as a2)`, `a` projects to both `a`
+    /// and `a2`. Non-leaf expressions are not projected through their
+    /// children.
+    pub fn project_expr_all(
+        &self,
+        mapping: &ProjectionMapping,
+        expr: &Arc<dyn PhysicalExpr>,
+    ) -> Vec<Arc<dyn PhysicalExpr>> {
+        let cls = self.equivalence_class(expr);
+        mapping
+            .iter()
+            .filter(|(source, _)| {
+                (*source).eq(expr) || cls.is_some_and(|cls| cls.contains(*source))
+            })
+            .flat_map(|(_, targets)| targets.iter().map(|(target, _)| Arc::clone(target)))
+            .collect()
+    }
+
     /// Projects `expressions` according to the given projection mapping.
     /// This function is similar to [`Self::project_expr`], but projects multiple
     /// expressions at once more efficiently than calling `project_expr` for each
@@ -683,11 +1598,34 @@ impl EquivalenceGroup {
         })
     }
 
+    /// Projects the given ordering according to the given projection mapping
+    /// by projecting each sort expression like [`Self::project_expr`]. Returns
+    /// `None` if any of the sort expressions cannot be projected.
+    pub fn project_ordering(
+        &self,
+        mapping: &ProjectionMapping,
+        ordering: &LexOrdering,
+    ) -> Option<LexOrdering> {
+        let exprs = ordering.iter().map(|sort_expr| &sort_expr.expr);
+        let sort_exprs = self
+            .project_expressions(mapping, exprs)
+            .zip(ordering.iter())
+            .map(|(expr, sort_expr)| {
+                Some(PhysicalSortExpr::new(expr?, sort_expr.options))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        LexOrdering::new(sort_exprs)
+    }
+
     /// Projects this equivalence group according to the given projection mapping.
     pub fn project(&self, mapping: &ProjectionMapping) -> Self {
         let projected_classes = self.iter().map(|cls| {
-            let new_exprs = self.project_expressions(mapping, cls.iter());
-            EquivalenceClass::new(new_exprs.flatten())
+            let mut new_class = EquivalenceClass::with_capacity(cls.len());
+            for expr in self.project_expressions(mapping, cls.iter()).flatten() {
+                new_class.push(expr);
+            }
+            new_class.sources.clone_from(&cls.sources);
+            new_class
         });
 
         // The key is the source expression, and the value is the equivalence
@@ -737,7 +1675,7 @@ impl EquivalenceGroup {
         if let Some(lit) = expr.as_any().downcast_ref::<Literal>() {
             return Some(AcrossPartitions::Uniform(Some(lit.value().clone())));
         }
-        if let Some(cls) = self.get_equivalence_class(expr)
+        if let Some(cls) = self.equivalence_class(expr)
             && cls.constant.is_some()
         {
             return cls.constant.clone();
@@ -755,16 +1693,143 @@ impl EquivalenceGroup {
         Some(AcrossPartitions::Heterogeneous)
     }
 
+    /// Simplifies the given physical expression by first normalizing it (see
+    /// [`Self::normalize_expr`]), and then replacing every subexpression with
+    /// a known uniform value with a literal. Such values come either from the
+    /// equivalence classes in this group, or from the given `constants`.
+    ///
+    /// If columns `a` and `b` are known to be equal, and `b` is known to be
+    /// the constant `5`, this function would turn `a + 1` into `5 + 1`.
+    pub fn simplify_expr(
+        &self,
+        expr: Arc<dyn PhysicalExpr>,
+        constants: &[ConstExpr],
+    ) -> Arc<dyn PhysicalExpr> {
+        // Constants are matched against normalized subexpressions, so they
+        // need to be normalized as well:
+        let constants = constants
+            .iter()
+            .filter_map(|const_expr| {
+                let value = const_expr.value()?;
+                Some((self.normalize_expr(Arc::clone(&const_expr.expr)), value))
+            })
+            .collect::<Vec<_>>();
+        self.normalize_expr(expr)
+            .transform_down(|expr| {
+                if expr.as_any().is::<Literal>() {
+                    return Ok(Transformed::no(expr));
+                }
+                let class_value =
+                    self.equivalence_class(&expr)
+                        .and_then(|cls| match &cls.constant {
+                            Some(AcrossPartitions::Uniform(value)) => value.as_ref(),
+                            _ => None,
+                        });
+                let value = class_value.or_else(|| {
+                    constants.iter().find_map(|(const_expr, value)| {
+                        const_expr.eq(&expr).then_some(*value)
+                    })
+                });
+                Ok(match value {
+                    Some(value) => {
+                        Transformed::yes(Arc::new(Literal::new(value.clone()))
+                            as Arc<dyn PhysicalExpr>)
+                    }
+                    None => Transformed::no(expr),
+                })
+            })
+            .unwrap()
+            // The unwrap above is safe because the closure always returns `Ok`.
+            .data
+    }
+
     /// Returns the equivalence class containing `expr`. If no equivalence class
     /// contains `expr`, returns `None`.
-    pub fn get_equivalence_class(
+    pub fn equivalence_class(
         &self,
         expr: &Arc<dyn PhysicalExpr>,
     ) -> Option<&EquivalenceClass> {
         self.map.get(expr).map(|idx| &self.classes[*idx])
     }
 
+    /// Returns the equivalence class containing `expr`. If no equivalence class
+    /// contains `expr`, returns `None`.
+    #[deprecated(since = "52.0.0", note = "Use equivalence_class instead")]
+    pub fn get_equivalence_class(
+        &self,
+        expr: &Arc<dyn PhysicalExpr>,
+    ) -> Option<&EquivalenceClass> {
+        self.equivalence_class(expr)
+    }
+
+    /// Checks whether `expr` belongs to any equivalence class in this group.
+    pub fn contains_expr(&self, expr: &Arc<dyn PhysicalExpr>) -> bool {
+        self.map.contains_key(expr)
+    }
+
+    /// Returns a new equivalence group where every member of every class is
+    /// rewritten with `f` (e.g. to replace a column reference with a cast).
+    /// Classes that end up sharing expressions are merged, and classes that
+    /// become trivial are removed.
+    pub fn map_expressions<F>(&self, f: F) -> EquivalenceGroup
+    where
+        F: Fn(&Arc<dyn PhysicalExpr>) -> Arc<dyn PhysicalExpr>,
+    {
+        Self::new(self.classes.iter().map(|cls| cls.map(&f)))
+    }
+
+    /// Returns a new equivalence group where every column reference that is a
+    /// key in `renames` is replaced with the corresponding value, e.g. after
+    /// a projection that only aliases columns. See [`Self::map_expressions`]
+    /// for how the resulting classes are merged and pruned.
+    pub fn rename_columns(&self, renames: &HashMap<Column, Column>) -> EquivalenceGroup {
+        self.map_expressions(|expr| {
+            Arc::clone(expr)
+                .transform(|expr| {
+                    let renamed = expr
+                        .as_any()
+                        .downcast_ref::<Column>()
+                        .and_then(|col| renames.get(col));
+                    Ok(match renamed {
+                        Some(col) => Transformed::yes(Arc::new(col.clone()) as _),
+                        None => Transformed::no(expr),
+                    })
+                })
+                .unwrap()
+                // The unwrap above is safe because the closure always returns `Ok`.
+                .data
+        })
+    }
+
+    /// Adds the given offset to all columns in the expressions of this group,
+    /// see [`EquivalenceClass::try_with_offset`]. This is used when schemas
+    /// are appended, e.g. for the right side of a join.
+    pub fn try_with_offset(&self, offset: isize) -> Result<Self> {
+        let classes = self
+            .iter()
+            .map(|cls| cls.try_with_offset(offset))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self::new(classes))
+    }
+
     /// Combine equivalence groups of the given join children.
+    ///
+    /// For outer joins, unmatched rows pad all columns of the non-preserved
+    /// (nullable) side(s) with nulls together, so equalities between these
+    /// columns still hold. However, they are no longer constant, nor equal to
+    /// any other expression (e.g. a literal, or `coalesce(a, 0)`). Therefore,
+    /// classes of the nullable side(s) only keep their column members, and
+    /// lose their constant-ness.
+    ///
+    /// For `LeftMark` (`RightMark`) joins, the output consists of the left
+    /// (right) columns followed by a boolean mark column. Its index is the
+    /// number of columns of the preserved side: `left_size` for `LeftMark`,
+    /// and the width of the right side for `RightMark`. The mark column is not
+    /// equal to any other column, so the group of the preserved side is
+    /// returned as is; its column indices remain valid in the output.
+    ///
+    /// Debug builds check that left side column indices are below `left_size`;
+    /// use [`Self::try_join`] to validate the sizes of both sides.
     pub fn join(
         &self,
         right_equivalences: &Self,
@@ -774,14 +1839,22 @@ impl EquivalenceGroup {
     ) -> Result<Self> {
         let group = match join_type {
             JoinType::Inner | JoinType::Left | JoinType::Full | JoinType::Right => {
-                let mut result = Self::new(
-                    self.iter().cloned().chain(
-                        right_equivalences
-                            .iter()
-                            .map(|cls| cls.try_with_offset(left_size as _))
-                            .collect::<Result<Vec<_>>>()?,
-                    ),
+                debug_assert!(
+                    self.left_join_columns_max(on)
+                        .is_none_or(|idx| idx < left_size),
+                    "Left side column index out of bounds for a join with {left_size} left columns"
                 );
+                let left_classes = match join_type {
+                    JoinType::Inner | JoinType::Left => self.classes.clone(),
+                    _ => self.null_padded_classes(),
+                };
+                let right_equivalences =
+                    right_equivalences.try_with_offset(left_size as _)?;
+                let right_classes = match join_type {
+                    JoinType::Inner | JoinType::Right => right_equivalences.classes,
+                    _ => right_equivalences.null_padded_classes(),
+                };
+                let mut result = Self::new(left_classes.into_iter().chain(right_classes));
                 // In we have an inner join, expressions in the "on" condition
                 // are equal in the resulting table.
                 if join_type == &JoinType::Inner {
@@ -795,14 +1868,153 @@ impl EquivalenceGroup {
                 }
                 result
             }
-            JoinType::LeftSemi | JoinType::LeftAnti | JoinType::LeftMark => self.clone(),
-            JoinType::RightSemi | JoinType::RightAnti | JoinType::RightMark => {
-                right_equivalences.clone()
+            JoinType::LeftSemi => {
+                let mut result = self.clone();
+                let pairs = on.iter().map(|(lhs, rhs)| (rhs, lhs));
+                result.add_semi_join_equalities(right_equivalences, pairs);
+                result
+            }
+            JoinType::RightSemi => {
+                let mut result = right_equivalences.clone();
+                let pairs = on.iter().map(|(lhs, rhs)| (lhs, rhs));
+                result.add_semi_join_equalities(self, pairs);
+                result
             }
+            // Rows surviving anti joins have no match, so the "on" condition
+            // tells nothing about them. Mark columns are appended after the
+            // preserved side's columns, so they do not shift any of the
+            // existing column indices:
+            JoinType::LeftAnti | JoinType::LeftMark => self.clone(),
+            JoinType::RightAnti | JoinType::RightMark => right_equivalences.clone(),
         };
         Ok(group)
     }
 
+    /// Returns the classes of this group as they hold for the nullable side of
+    /// an outer join; i.e. restricted to their column members, and without
+    /// constant values. See [`Self::join`] for details.
+    fn null_padded_classes(&self) -> Vec<EquivalenceClass> {
+        self.iter()
+            .map(|cls| {
+                let mut padded = EquivalenceClass::new(
+                    cls.iter()
+                        .filter(|expr| expr.as_any().is::<Column>())
+                        .cloned(),
+                );
+                padded.sources.clone_from(&cls.sources);
+                padded
+            })
+            .collect()
+    }
+
+    /// Like [`Self::join`], but first checks that `left_size` is consistent
+    /// with the column indices involved, which catches join builders passing
+    /// a wrong size. For joins whose output consists of the left columns
+    /// followed by the right columns (i.e. inner and outer joins), all left
+    /// side column indices must be below `left_size`, and all right side
+    /// column indices plus `left_size` must be below `output_size`, which is
+    /// the number of columns in the join output. Otherwise, returns an error.
+    pub fn try_join(
+        &self,
+        right_equivalences: &Self,
+        join_type: &JoinType,
+        left_size: usize,
+        output_size: usize,
+        on: &[(PhysicalExprRef, PhysicalExprRef)],
+    ) -> Result<Self> {
+        if matches!(
+            join_type,
+            JoinType::Inner | JoinType::Left | JoinType::Full | JoinType::Right
+        ) {
+            if let Some(idx) = self
+                .left_join_columns_max(on)
+                .filter(|idx| *idx >= left_size)
+            {
+                return internal_err!(
+                    "Left side column index {idx} is out of bounds for a join with {left_size} left columns"
+                );
+            }
+            let right_exprs = right_equivalences
+                .iter()
+                .flat_map(|cls| cls.iter())
+                .chain(on.iter().map(|(_, rhs)| rhs));
+            if let Some(idx) =
+                max_column_index(right_exprs).filter(|idx| idx + left_size >= output_size)
+            {
+                return internal_err!(
+                    "Right side column index {idx} is out of bounds for a join with {left_size} left and {output_size} output columns"
+                );
+            }
+        }
+        self.join(right_equivalences, join_type, left_size, on)
+    }
+
+    /// Returns the largest column index referenced by this group, which holds
+    /// the equivalences of the left side of a join, and the left side of the
+    /// given join condition.
+    fn left_join_columns_max(
+        &self,
+        on: &[(PhysicalExprRef, PhysicalExprRef)],
+    ) -> Option<usize> {
+        let left_exprs = self
+            .iter()
+            .flat_map(|cls| cls.iter())
+            .chain(on.iter().map(|(lhs, _)| lhs));
+        max_column_index(left_exprs)
+    }
+
+    /// Adds the equalities that hold after a semi join to this group, which
+    /// contains the equivalences of the preserved side. Every surviving row
+    /// matched some row of the filtering side, so preserved side expressions
+    /// joined with equal filtering side expressions (according to the
+    /// `filtering` group) are equal. For example, given the condition
+    /// `l.a = r.x AND l.a = r.y`, we know that `r.x = r.y` for all rows of a
+    /// right semi join. Each of the given pairs consists of a filtering side
+    /// expression and a preserved side expression.
+    fn add_semi_join_equalities<'a>(
+        &mut self,
+        filtering: &Self,
+        pairs: impl IntoIterator<Item = (&'a PhysicalExprRef, &'a PhysicalExprRef)>,
+    ) {
+        // Maps normalized filtering side expressions to the first preserved
+        // side expression they are joined with:
+        let mut keys = IndexMap::<_, &PhysicalExprRef>::new();
+        for (key, expr) in pairs {
+            let key = filtering.normalize_expr(Arc::clone(key));
+            if let Some(first) = keys.get(&key) {
+                self.add_equal_conditions(Arc::clone(first), Arc::clone(expr));
+            } else {
+                keys.insert(key, expr);
+            }
+        }
+    }
+
+    /// Returns the equivalences that hold in both `self` and `other`, e.g.
+    /// the equalities that survive a `UNION` of two inputs. Every pair of
+    /// expressions within a class of `self` is kept if `other` also considers
+    /// them equal (see [`Self::exprs_equal`]).
+    pub fn intersect(&self, other: &EquivalenceGroup) -> EquivalenceGroup {
+        let mut result = Self::default();
+        for cls in self.iter() {
+            for (idx, lhs) in cls.iter().enumerate() {
+                for rhs in cls.iter().skip(idx + 1) {
+                    if other.exprs_equal(lhs, rhs) {
+                        result.add_equal_conditions(Arc::clone(lhs), Arc::clone(rhs));
+                    }
+                }
+            }
+        }
+        result
+    }
+
+    /// Returns the equivalences that hold in `self` or `other`, i.e. all
+    /// classes of both groups, with classes sharing expressions merged. Unlike
+    /// [`Self::intersect`], this is only valid when the equivalences of both
+    /// groups are known to hold for the same rows.
+    pub fn union(&self, other: &EquivalenceGroup) -> EquivalenceGroup {
+        Self::new(self.classes.iter().chain(other.classes.iter()).cloned())
+    }
+
     /// Checks if two expressions are equal directly or through equivalence
     /// classes. For complex expressions (e.g. `a + b`), checks that the
     /// expression trees are structurally identical and their leaf nodes are
@@ -812,6 +2024,44 @@ impl EquivalenceGroup {
         left: &Arc<dyn PhysicalExpr>,
         right: &Arc<dyn PhysicalExpr>,
     ) -> bool {
+        self.exprs_equal_impl(left, right, None)
+    }
+
+    /// Like [`Self::exprs_equal`], but also takes the associativity of `+`
+    /// and `*` into account: chains of the same associative operator are
+    /// flattened into a multiset of operands, which are then compared
+    /// pairwise. For example, `(a + b) + c` and `a + (b + c)` are considered
+    /// equal. Since operands are matched regardless of their positions, this
+    /// also takes commutativity into account; e.g. `a + b` and `b + a` are
+    /// considered equal too.
+    ///
+    /// This only applies to chains whose operands are all integers or
+    /// decimals according to `schema`, as floating point and temporal
+    /// arithmetic can not be regrouped. Other chains, as well as expressions
+    /// using any other operator, are compared strictly.
+    pub fn exprs_equal_associative(
+        &self,
+        left: &Arc<dyn PhysicalExpr>,
+        right: &Arc<dyn PhysicalExpr>,
+        schema: &Schema,
+    ) -> bool {
+        self.exprs_equal_impl(left, right, Some(schema))
+    }
+
+    /// Implements [`Self::exprs_equal`], and [`Self::exprs_equal_associative`]
+    /// if the schema for checking operand types is given.
+    fn exprs_equal_impl(
+        &self,
+        left: &Arc<dyn PhysicalExpr>,
+        right: &Arc<dyn PhysicalExpr>,
+        associative: Option<&Schema>,
+    ) -> bool {
+        // Clones of the same expression are trivially equal, which avoids a
+        // potentially deep structural comparison:
+        if Arc::ptr_eq(left, right) {
+            return true;
+        }
+
         // Direct equality check
         if left.eq(right) {
             return true;
@@ -819,17 +2069,23 @@ impl EquivalenceGroup {
 
         // Check if expressions are equivalent through equivalence classes
         // We need to check both directions since expressions might be in different classes
-        if let Some(left_class) = self.get_equivalence_class(left)
+        if let Some(left_class) = self.equivalence_class(left)
             && left_class.contains(right)
         {
             return true;
         }
-        if let Some(right_class) = self.get_equivalence_class(right)
+        if let Some(right_class) = self.equivalence_class(right)
             && right_class.contains(left)
         {
             return true;
         }
 
+        if let Some(schema) = associative
+            && let Some(equal) = self.associative_operands_equal(left, right, schema)
+        {
+            return equal;
+        }
+
         // For non-leaf nodes, check structural equality
         let left_children = left.children();
         let right_children = right.children();
@@ -854,7 +2110,83 @@ impl EquivalenceGroup {
         left_children
             .into_iter()
             .zip(right_children)
-            .all(|(left_child, right_child)| self.exprs_equal(left_child, right_child))
+            .all(|(left_child, right_child)| {
+                self.exprs_equal_impl(left_child, right_child, associative)
+            })
+    }
+
+    /// If both `left` and `right` are chains of the same associative operator
+    /// over integer or decimal operands, returns whether their flattened
+    /// operands match one-to-one. Returns `None` if the expressions are not
+    /// such chains.
+    fn associative_operands_equal(
+        &self,
+        left: &Arc<dyn PhysicalExpr>,
+        right: &Arc<dyn PhysicalExpr>,
+        schema: &Schema,
+    ) -> Option<bool> {
+        let left_binary = left.as_any().downcast_ref::<BinaryExpr>()?;
+        let right_binary = right.as_any().downcast_ref::<BinaryExpr>()?;
+        let op = left_binary.op();
+        if op != right_binary.op() || !matches!(op, Operator::Plus | Operator::Multiply) {
+            return None;
+        }
+
+        let mut left_operands = vec![];
+        flatten_associative_chain(left, op, &mut left_operands);
+        let mut right_operands = vec![];
+        flatten_associative_chain(right, op, &mut right_operands);
+        let regroupable = left_operands.iter().chain(&right_operands).all(|operand| {
+            operand.data_type(schema).is_ok_and(|data_type| {
+                data_type.is_integer()
+                    || matches!(
+                        data_type,
+                        DataType::Decimal32(..)
+                            | DataType::Decimal64(..)
+                            | DataType::Decimal128(..)
+                            | DataType::Decimal256(..)
+                    )
+            })
+        });
+        if !regroupable {
+            return None;
+        }
+        if left_operands.len() != right_operands.len() {
+            return Some(false);
+        }
+
+        let mut matched = vec![false; right_operands.len()];
+        Some(left_operands.into_iter().all(|left_operand| {
+            right_operands
+                .iter()
+                .zip(matched.iter_mut())
+                .find(|(right_operand, matched)| {
+                    !**matched
+                        && self.exprs_equal_impl(
+                            left_operand,
+                            right_operand,
+                            Some(schema),
+                        )
+                })
+                .map(|(_, matched)| *matched = true)
+                .is_some()
+        }))
+    }
+}
+
+/// Collects the operands of a chain of binary expressions that all use `op`
+/// into `operands`, e.g. `[a, b, c]` for `(a + b) + c` when `op` is `+`.
+fn flatten_associative_chain<'a>(
+    expr: &'a Arc<dyn PhysicalExpr>,
+    op: &Operator,
+    operands: &mut Vec<&'a Arc<dyn PhysicalExpr>>,
+) {
+    match expr.as_any().downcast_ref::<BinaryExpr>() {
+        Some(binary) if binary.op() == op => {
+            flatten_associative_chain(binary.left(), op, operands);
+            flatten_associative_chain(binary.right(), op, operands);
+        }
+        _ => operands.push(expr),
     }
 }
 
@@ -878,69 +2210,558 @@ impl IntoIterator for EquivalenceGroup {
 impl Display for EquivalenceGroup {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         write!(f, "[")?;
-        let mut iter = self.iter();
-        if let Some(cls) = iter.next() {
-            write!(f, "{cls}")?;
-        }
-        for cls in iter {
-            write!(f, ", {cls}")?;
+        for (idx, cls) in self.iter().enumerate() {
+            if idx > 0 {
+                write!(f, ", ")?;
+            }
+            if f.alternate() {
+                write!(f, "{cls:#}")?;
+            } else {
+                write!(f, "{cls}")?;
+            }
         }
         write!(f, "]")
     }
 }
 
+/// A memoization layer over [`EquivalenceGroup::normalize_expr`]. Planners
+/// often normalize the same expressions repeatedly; this cache remembers the
+/// normalized form of every expression it has seen. Since the underlying
+/// group is borrowed immutably, cached results never go stale.
+#[derive(Debug)]
+pub struct NormalizerCache<'a> {
+    /// The equivalence group to normalize expressions with.
+    group: &'a EquivalenceGroup,
+    /// A mapping from expressions to their normalized forms.
+    cache: HashMap<Arc<dyn PhysicalExpr>, Arc<dyn PhysicalExpr>>,
+}
+
+impl<'a> NormalizerCache<'a> {
+    /// Creates an empty cache for the given equivalence group.
+    pub fn new(group: &'a EquivalenceGroup) -> Self {
+        Self {
+            group,
+            cache: HashMap::new(),
+        }
+    }
+
+    /// Returns the equivalence group this cache normalizes with.
+    pub fn group(&self) -> &'a EquivalenceGroup {
+        self.group
+    }
+
+    /// Normalizes the given physical expression according to the underlying
+    /// group, reusing the cached result if `expr` was normalized before. See
+    /// [`EquivalenceGroup::normalize_expr`] for details.
+    pub fn normalize_expr(
+        &mut self,
+        expr: Arc<dyn PhysicalExpr>,
+    ) -> Arc<dyn PhysicalExpr> {
+        if let Some(normalized) = self.cache.get(&expr) {
+            return Arc::clone(normalized);
+        }
+        let normalized = self.group.normalize_expr(Arc::clone(&expr));
+        self.cache.insert(expr, Arc::clone(&normalized));
+        normalized
+    }
+
+    /// Returns the number of cached expressions.
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// Checks whether the cache is empty.
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+}
+
 impl From<Vec<EquivalenceClass>> for EquivalenceGroup {
     fn from(classes: Vec<EquivalenceClass>) -> Self {
-        let mut result = Self {
-            map: classes
+        Self::new_checked(classes).0
+    }
+}
+
+/// Serialization of equivalence information, e.g. for JSON debug dumps. Since
+/// [`PhysicalExpr`] trait objects and [`ScalarValue`]s do not implement the
+/// `serde` traits, they are represented by their `Display` form. This form is
+/// meant for diagnostics only, hence there is no deserialization support.
+#[cfg(feature = "serde")]
+mod serialize {
+    use super::*;
+
+    use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+    /// Renders the given (optional) scalar value for diagnostics.
+    fn display_value(value: &Option<ScalarValue>) -> Option<String> {
+        value.as_ref().map(|value| value.to_string())
+    }
+
+    impl Serialize for AcrossPartitions {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            match self {
+                AcrossPartitions::Heterogeneous => serializer.serialize_unit_variant(
+                    "AcrossPartitions",
+                    0,
+                    "Heterogeneous",
+                ),
+                AcrossPartitions::Uniform(value) => serializer.serialize_newtype_variant(
+                    "AcrossPartitions",
+                    1,
+                    "Uniform",
+                    &display_value(value),
+                ),
+            }
+        }
+    }
+
+    impl Serialize for PartitionValues {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            match self {
+                PartitionValues::Uniform(value) => serializer.serialize_newtype_variant(
+                    "PartitionValues",
+                    0,
+                    "Uniform",
+                    &display_value(value),
+                ),
+                PartitionValues::Heterogeneous(values) => serializer
+                    .serialize_newtype_variant(
+                        "PartitionValues",
+                        1,
+                        "Heterogeneous",
+                        &values.iter().map(display_value).collect::<Vec<_>>(),
+                    ),
+            }
+        }
+    }
+
+    impl Serialize for ConstExpr {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut state = serializer.serialize_struct("ConstExpr", 2)?;
+            state.serialize_field("expr", &self.expr.to_string())?;
+            state.serialize_field("across_partitions", &self.partition_values)?;
+            state.end()
+        }
+    }
+
+    impl Serialize for EquivalenceClass {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let exprs = self.iter().map(|expr| expr.to_string()).collect::<Vec<_>>();
+            let mut state = serializer.serialize_struct("EquivalenceClass", 3)?;
+            state.serialize_field("exprs", &exprs)?;
+            state.serialize_field("constant", &self.constant)?;
+            let sources = self
+                .sources
                 .iter()
-                .enumerate()
-                .flat_map(|(idx, cls)| {
-                    cls.iter().map(move |expr| (Arc::clone(expr), idx))
-                })
-                .collect(),
-            classes,
-        };
-        result.remove_redundant_entries();
-        result
+                .map(AsRef::as_ref)
+                .collect::<Vec<&str>>();
+            state.serialize_field("sources", &sources)?;
+            state.end()
+        }
+    }
+
+    impl Serialize for EquivalenceGroup {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            // The lookup table is derived from the classes:
+            let mut state = serializer.serialize_struct("EquivalenceGroup", 1)?;
+            state.serialize_field("classes", &self.classes)?;
+            state.end()
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::any::Any;
+    use std::collections::HashSet;
+
     use super::*;
+    use crate::ScalarFunctionExpr;
     use crate::equivalence::tests::create_test_params;
-    use crate::expressions::{BinaryExpr, Column, Literal, binary, col, lit};
-    use arrow::datatypes::{DataType, Field, Schema};
+    use crate::expressions::{BinaryExpr, Column, Literal, binary, case, col, lit};
+    use arrow::array::RecordBatch;
+    use arrow::compute::SortOptions;
+    use arrow::datatypes::{DataType, Field, IntervalUnit, Schema};
+    use datafusion_common::config::ConfigOptions;
 
-    use datafusion_common::{Result, ScalarValue};
-    use datafusion_expr::Operator;
+    use datafusion_common::{Result, ScalarValue, assert_contains};
+    use datafusion_expr::{ColumnarValue, Operator};
+    use datafusion_physical_expr_common::physical_expr::fmt_sql;
 
     #[test]
-    fn test_bridge_groups() -> Result<()> {
-        // First entry in the tuple is argument, second entry is the bridged result
-        let test_cases = vec![
-            // ------- TEST CASE 1 -----------//
-            (
-                vec![vec![1, 2, 3], vec![2, 4, 5], vec![11, 12, 9], vec![7, 6, 5]],
-                // Expected is compared with set equality. Order of the specific results may change.
-                vec![vec![1, 2, 3, 4, 5, 6, 7], vec![9, 11, 12]],
-            ),
-            // ------- TEST CASE 2 -----------//
-            (
-                vec![vec![1, 2, 3], vec![3, 4, 5], vec![9, 8, 7], vec![7, 6, 5]],
-                // Expected
-                vec![vec![1, 2, 3, 4, 5, 6, 7, 8, 9]],
-            ),
+    fn test_const_expr_partition_values() {
+        let col_a = Arc::new(Column::new("a", 0)) as Arc<dyn PhysicalExpr>;
+        let five = ScalarValue::Int32(Some(5));
+
+        // Uniform construction:
+        let uniform = ConstExpr::new(
+            Arc::clone(&col_a),
+            PartitionValues::Uniform(Some(five.clone())),
+        );
+        assert_eq!(
+            uniform.partition_values,
+            PartitionValues::Uniform(Some(five.clone()))
+        );
+        assert_eq!(
+            AcrossPartitions::from(uniform.partition_values()),
+            AcrossPartitions::Uniform(Some(five.clone()))
+        );
+        assert_eq!(uniform.to_string(), "a@0(uniform: 5)");
+
+        // Heterogeneous construction with per-partition values:
+        let values = vec![
+            Some(ScalarValue::Int32(Some(1))),
+            None,
+            Some(ScalarValue::Int32(Some(3))),
         ];
-        for (entries, expected) in test_cases {
-            let entries = entries
-                .into_iter()
-                .map(|entry| {
-                    entry.into_iter().map(|idx| {
-                        let c = Column::new(format!("col_{idx}").as_str(), idx);
-                        Arc::new(c) as _
-                    })
+        let heterogeneous = ConstExpr::new(
+            Arc::clone(&col_a),
+            PartitionValues::Heterogeneous(values.clone()),
+        );
+        assert_eq!(
+            heterogeneous.partition_values,
+            PartitionValues::Heterogeneous(values)
+        );
+        assert_eq!(
+            AcrossPartitions::from(heterogeneous.partition_values()),
+            AcrossPartitions::Heterogeneous
+        );
+        assert_eq!(
+            heterogeneous.partition_values,
+            AcrossPartitions::Heterogeneous
+        );
+        assert_eq!(
+            heterogeneous.to_string(),
+            "a@0(heterogeneous: [1, unknown, 3])"
+        );
+
+        // The coarser `AcrossPartitions` is still accepted:
+        let unknown = ConstExpr::new(Arc::clone(&col_a), AcrossPartitions::Heterogeneous);
+        assert_eq!(unknown.partition_values, PartitionValues::default());
+        assert_eq!(unknown.to_string(), "a@0(heterogeneous)");
+        let uniform_again = ConstExpr::from(Arc::clone(&col_a))
+            .with_across_partitions(AcrossPartitions::Uniform(Some(five.clone())));
+        assert_eq!(uniform_again, uniform);
+
+        // Literals always have uniform values:
+        let literal = ConstExpr::new(
+            lit(5),
+            PartitionValues::Heterogeneous(vec![Some(ScalarValue::Int32(Some(6)))]),
+        );
+        assert_eq!(
+            literal.partition_values,
+            PartitionValues::Uniform(Some(five.clone()))
+        );
+
+        // Only uniform constants with known values have a value:
+        assert_eq!(uniform.value(), Some(&five));
+        assert_eq!(literal.value(), Some(&five));
+        assert_eq!(heterogeneous.value(), None);
+        assert_eq!(unknown.value(), None);
+        let uniform_unknown =
+            ConstExpr::new(Arc::clone(&col_a), AcrossPartitions::Uniform(None));
+        assert_eq!(uniform_unknown.value(), None);
+
+        // Uniformity does not depend on whether values are known:
+        for const_expr in [&uniform, &literal, &uniform_unknown] {
+            assert!(const_expr.is_uniform());
+            assert!(!const_expr.is_heterogeneous());
+        }
+        for const_expr in [&heterogeneous, &unknown] {
+            assert!(const_expr.is_heterogeneous());
+            assert!(!const_expr.is_uniform());
+        }
+
+        // Equivalence classes only track the coarse form:
+        let mut group = EquivalenceGroup::default();
+        group.add_constant(heterogeneous);
+        assert_eq!(
+            group.is_expr_constant(&col_a),
+            Some(AcrossPartitions::Heterogeneous)
+        );
+    }
+
+    #[test]
+    fn test_const_expr_per_partition_values() {
+        let col_a = Arc::new(Column::new("a", 0)) as Arc<dyn PhysicalExpr>;
+        let int = |value| Some(ScalarValue::Int32(Some(value)));
+
+        // A constant with a different value in each of three partitions:
+        let values = vec![int(1), None, int(3)];
+        let heterogeneous = ConstExpr::new(
+            Arc::clone(&col_a),
+            PartitionValues::Heterogeneous(values.clone()),
+        );
+        assert!(heterogeneous.is_heterogeneous());
+        assert_eq!(
+            heterogeneous.per_partition_values(),
+            Some(values.as_slice())
+        );
+        assert_eq!(heterogeneous.per_partition_values().unwrap().len(), 3);
+        assert_eq!(heterogeneous.value(), None);
+
+        // Untracked per-partition values and uniform constants have none:
+        let unknown = ConstExpr::from(Arc::clone(&col_a));
+        assert!(unknown.is_heterogeneous());
+        assert_eq!(unknown.per_partition_values(), None);
+        let uniform = ConstExpr::uniform(col_a, ScalarValue::Int32(Some(1)));
+        assert_eq!(uniform.per_partition_values(), None);
+        let literal = ConstExpr::new(lit(1), PartitionValues::Heterogeneous(values));
+        assert_eq!(literal.per_partition_values(), None);
+    }
+
+    #[test]
+    fn test_const_expr_uniform() {
+        let col_a = Arc::new(Column::new("a", 0)) as Arc<dyn PhysicalExpr>;
+        let five = ScalarValue::Int32(Some(5));
+
+        let known = ConstExpr::uniform(Arc::clone(&col_a), five.clone());
+        assert_eq!(
+            known.partition_values,
+            PartitionValues::Uniform(Some(five.clone()))
+        );
+        assert_eq!(known.value(), Some(&five));
+        assert_eq!(
+            known,
+            ConstExpr::new(
+                Arc::clone(&col_a),
+                AcrossPartitions::Uniform(Some(five.clone()))
+            )
+        );
+
+        let unknown = ConstExpr::uniform_unknown(Arc::clone(&col_a));
+        assert_eq!(unknown.partition_values, PartitionValues::Uniform(None));
+        assert!(unknown.is_uniform());
+        assert_eq!(unknown.value(), None);
+
+        // Literals keep their own value:
+        let literal = ConstExpr::uniform(lit(1), five);
+        assert_eq!(literal.value(), Some(&ScalarValue::Int32(Some(1))));
+    }
+
+    #[test]
+    fn test_const_expr_hash() {
+        let hash = |const_expr: &ConstExpr| {
+            let mut hasher = DefaultHasher::new();
+            const_expr.hash(&mut hasher);
+            hasher.finish()
+        };
+        let col_a = Arc::new(Column::new("a", 0)) as Arc<dyn PhysicalExpr>;
+        let uniform = |value| {
+            ConstExpr::new(
+                Arc::clone(&col_a),
+                AcrossPartitions::Uniform(Some(ScalarValue::Int32(Some(value)))),
+            )
+        };
+
+        assert_eq!(uniform(5), uniform(5));
+        assert_eq!(hash(&uniform(5)), hash(&uniform(5)));
+        let heterogeneous = ConstExpr::from(Arc::clone(&col_a));
+        assert_ne!(uniform(5), heterogeneous);
+        assert_ne!(hash(&uniform(5)), hash(&heterogeneous));
+        assert_ne!(hash(&uniform(5)), hash(&uniform(6)));
+
+        // Constants from multiple sources can be deduplicated:
+        let constants = [uniform(5), heterogeneous.clone(), uniform(5), heterogeneous]
+            .into_iter()
+            .collect::<HashSet<_>>();
+        assert_eq!(constants.len(), 2);
+    }
+
+    #[test]
+    fn test_const_expr_normalize() {
+        let col_a = Arc::new(Column::new("a", 0)) as Arc<dyn PhysicalExpr>;
+        let col_b = Arc::new(Column::new("b", 1)) as Arc<dyn PhysicalExpr>;
+        let col_c = Arc::new(Column::new("c", 2)) as Arc<dyn PhysicalExpr>;
+        let eq_group = EquivalenceGroup::new([EquivalenceClass::new([
+            Arc::clone(&col_a),
+            Arc::clone(&col_c),
+        ])]);
+        let values = PartitionValues::Heterogeneous(vec![
+            Some(ScalarValue::Int32(Some(1))),
+            Some(ScalarValue::Int32(Some(2))),
+        ]);
+
+        let const_c = ConstExpr::new(Arc::clone(&col_c), values.clone());
+        let normalized = const_c.normalize(&eq_group);
+        assert!(normalized.expr.eq(&col_a));
+        assert_eq!(normalized.partition_values, values);
+        assert_eq!(
+            normalized,
+            ConstExpr::new(Arc::clone(&col_a), values.clone()).normalize(&eq_group)
+        );
+
+        // Expressions outside of the group are left as is:
+        let const_b = ConstExpr::new(col_b, values);
+        assert_eq!(const_b.normalize(&eq_group), const_b);
+    }
+
+    #[test]
+    fn test_const_expr_set() {
+        let col_a = Arc::new(Column::new("a", 0)) as Arc<dyn PhysicalExpr>;
+        let col_b = Arc::new(Column::new("b", 1)) as Arc<dyn PhysicalExpr>;
+        let col_c = Arc::new(Column::new("c", 2)) as Arc<dyn PhysicalExpr>;
+        let const_exprs = [
+            ConstExpr::from(Arc::clone(&col_a)),
+            ConstExpr::new(Arc::clone(&col_b), AcrossPartitions::Uniform(None)),
+        ];
+
+        let mut set = ConstExprSet::new(&const_exprs);
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&col_a));
+        assert!(set.contains(&col_b));
+        assert!(!set.contains(&col_c));
+        // Lookups are structural, not by pointer:
+        assert!(set.contains(&(Arc::new(Column::new("a", 0)) as _)));
+
+        assert!(set.insert(&ConstExpr::from(Arc::clone(&col_c))));
+        assert!(!set.insert(&ConstExpr::from(Arc::clone(&col_a))));
+        assert!(set.contains(&col_c));
+        assert_eq!(set.iter().count(), 3);
+        assert!(ConstExprSet::default().is_empty());
+    }
+
+    #[test]
+    fn test_constant_closure() {
+        let col_a = Arc::new(Column::new("a", 0)) as Arc<dyn PhysicalExpr>;
+        let col_b = Arc::new(Column::new("b", 1)) as Arc<dyn PhysicalExpr>;
+        let col_c = Arc::new(Column::new("c", 2)) as Arc<dyn PhysicalExpr>;
+        let col_d = Arc::new(Column::new("d", 3)) as Arc<dyn PhysicalExpr>;
+        let col_e = Arc::new(Column::new("e", 4)) as Arc<dyn PhysicalExpr>;
+        let group = EquivalenceGroup::new([
+            EquivalenceClass::new([
+                Arc::clone(&col_a),
+                Arc::clone(&col_b),
+                Arc::clone(&col_c),
+            ]),
+            EquivalenceClass::new([Arc::clone(&col_d), Arc::clone(&col_e)]),
+        ]);
+
+        let uniform = AcrossPartitions::Uniform(Some(ScalarValue::from(5)));
+        let consts = [ConstExpr::new(Arc::clone(&col_a), uniform.clone())];
+        let closure = group.constant_closure(&consts);
+        let expected = [&col_a, &col_b, &col_c]
+            .map(|expr| ConstExpr::new(Arc::clone(expr), uniform.clone()));
+        assert_eq!(closure, expected);
+
+        // Constants outside of any class, or already known, are kept as is:
+        let consts = [
+            ConstExpr::from(Arc::clone(&col_e)),
+            ConstExpr::new(Arc::clone(&col_d), uniform.clone()),
+            ConstExpr::new(Arc::new(Column::new("f", 5)), uniform.clone()),
+        ];
+        let closure = group.constant_closure(&consts);
+        assert_eq!(closure, consts);
+        assert!(group.constant_closure(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_constant_closure_keeps_partition_values() {
+        let col_a = Arc::new(Column::new("a", 0)) as Arc<dyn PhysicalExpr>;
+        let col_b = Arc::new(Column::new("b", 1)) as Arc<dyn PhysicalExpr>;
+        let group = EquivalenceGroup::new([EquivalenceClass::new([
+            Arc::clone(&col_a),
+            Arc::clone(&col_b),
+        ])]);
+        let five = AcrossPartitions::Uniform(Some(ScalarValue::from(5)));
+        let constants =
+            group.constant_closure(&[ConstExpr::new(Arc::clone(&col_b), five.clone())]);
+        assert_eq!(
+            constants,
+            [
+                ConstExpr::new(col_b, five.clone()),
+                ConstExpr::new(col_a, five.clone()),
+            ]
+        );
+        assert!(constants.iter().all(|c| c.partition_values == five));
+    }
+
+    #[test]
+    fn test_const_expr_try_merge() {
+        let col_a = Arc::new(Column::new("a", 0)) as Arc<dyn PhysicalExpr>;
+        let col_b = Arc::new(Column::new("b", 1)) as Arc<dyn PhysicalExpr>;
+        let uniform = |value: Option<i32>| {
+            AcrossPartitions::Uniform(value.map(|v| ScalarValue::Int32(Some(v))))
+        };
+        let heterogeneous = AcrossPartitions::Heterogeneous;
+
+        let test_cases = [
+            (uniform(Some(5)), uniform(Some(5)), uniform(Some(5))),
+            (uniform(Some(5)), uniform(Some(6)), heterogeneous.clone()),
+            (
+                uniform(Some(5)),
+                heterogeneous.clone(),
+                heterogeneous.clone(),
+            ),
+            (
+                heterogeneous.clone(),
+                uniform(Some(5)),
+                heterogeneous.clone(),
+            ),
+            (
+                heterogeneous.clone(),
+                heterogeneous.clone(),
+                heterogeneous.clone(),
+            ),
+            // Unknown values may differ between the two sides:
+            (uniform(None), uniform(None), heterogeneous.clone()),
+            (uniform(None), uniform(Some(5)), heterogeneous.clone()),
+        ];
+        for (lhs, rhs, expected) in test_cases {
+            let lhs = ConstExpr::new(Arc::clone(&col_a), lhs);
+            let rhs = ConstExpr::new(Arc::clone(&col_a), rhs);
+            let merged = lhs.try_merge(&rhs).unwrap();
+            assert!(merged.expr.eq(&col_a));
+            assert_eq!(
+                merged.partition_values, expected,
+                "merging {lhs} with {rhs}"
+            );
+        }
+
+        // Constants of different expressions can not be merged:
+        let lhs = ConstExpr::new(Arc::clone(&col_a), uniform(Some(5)));
+        let rhs = ConstExpr::new(col_b, uniform(Some(5)));
+        assert!(lhs.try_merge(&rhs).is_none());
+    }
+
+    #[test]
+    fn test_bridge_groups() -> Result<()> {
+        // First entry in the tuple is argument, second entry is the bridged result
+        let test_cases = vec![
+            // ------- TEST CASE 1 -----------//
+            (
+                vec![vec![1, 2, 3], vec![2, 4, 5], vec![11, 12, 9], vec![7, 6, 5]],
+                // Expected is compared with set equality. Order of the specific results may change.
+                vec![vec![1, 2, 3, 4, 5, 6, 7], vec![9, 11, 12]],
+            ),
+            // ------- TEST CASE 2 -----------//
+            (
+                vec![vec![1, 2, 3], vec![3, 4, 5], vec![9, 8, 7], vec![7, 6, 5]],
+                // Expected
+                vec![vec![1, 2, 3, 4, 5, 6, 7, 8, 9]],
+            ),
+            // ------- TEST CASE 3 -----------//
+            (
+                vec![
+                    vec![1, 2],
+                    vec![10, 11],
+                    vec![3, 4],
+                    vec![5, 6],
+                    vec![4, 5],
+                    vec![2, 3],
+                ],
+                // Expected
+                vec![vec![1, 2, 3, 4, 5, 6], vec![10, 11]],
+            ),
+        ];
+        for (entries, expected) in test_cases {
+            let entries = entries
+                .into_iter()
+                .map(|entry| {
+                    entry.into_iter().map(|idx| {
+                        let c = Column::new(format!("col_{idx}").as_str(), idx);
+                        Arc::new(c) as _
+                    })
                 })
                 .map(EquivalenceClass::new)
                 .collect::<Vec<_>>();
@@ -967,6 +2788,200 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_normalizer_cache() -> Result<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+            Field::new("c", DataType::Int32, false),
+        ]);
+        let col_a = col("a", &schema)?;
+        let col_b = col("b", &schema)?;
+        let col_c = col("c", &schema)?;
+        let mut group = EquivalenceGroup::default();
+        group.add_equal_conditions(Arc::clone(&col_a), Arc::clone(&col_b));
+
+        let b_plus_c = binary(Arc::clone(&col_b), Operator::Plus, col_c, &schema)?;
+        let mut cache = NormalizerCache::new(&group);
+        assert!(cache.is_empty());
+        for expr in [&col_a, &col_b, &b_plus_c, &col_b, &b_plus_c] {
+            let normalized = group.normalize_expr(Arc::clone(expr));
+            assert!(cache.normalize_expr(Arc::clone(expr)).eq(&normalized));
+        }
+        // Repeated expressions are served from the cache:
+        assert_eq!(cache.len(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_normalize_exprs_if_changed() -> Result<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+            Field::new("c", DataType::Int32, false),
+            Field::new("d", DataType::Int32, false),
+        ]);
+        let col_a = col("a", &schema)?;
+        let col_b = col("b", &schema)?;
+        let col_c = col("c", &schema)?;
+        let col_d = col("d", &schema)?;
+        let mut group = EquivalenceGroup::default();
+        group.add_equal_conditions(Arc::clone(&col_a), Arc::clone(&col_b));
+
+        // Non-members and canonical expressions need no normalization:
+        let exprs = [Arc::clone(&col_c), Arc::clone(&col_d)];
+        assert!(group.normalize_exprs_if_changed(&exprs).is_none());
+        let exprs = [Arc::clone(&col_a), Arc::clone(&col_c)];
+        assert!(group.normalize_exprs_if_changed(&exprs).is_none());
+        assert!(group.normalize_exprs_if_changed(&[]).is_none());
+
+        // Otherwise, all expressions are returned in order:
+        let exprs = [Arc::clone(&col_c), col_b, Arc::clone(&col_d)];
+        let normalized = group.normalize_exprs_if_changed(&exprs).unwrap();
+        assert_eq!(normalized, [col_c, col_a, col_d]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_normalize_expr_ref() -> Result<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+            Field::new("c", DataType::Int32, false),
+        ]);
+        let col_a = col("a", &schema)?;
+        let col_b = col("b", &schema)?;
+        let col_c = col("c", &schema)?;
+        let mut group = EquivalenceGroup::default();
+        group.add_equal_conditions(Arc::clone(&col_a), Arc::clone(&col_b));
+
+        // Non-members and canonical expressions need no normalization:
+        assert!(group.normalize_expr_ref(&col_c).is_none());
+        assert!(group.normalize_expr_ref(&col_a).is_none());
+        let a_plus_c = binary(
+            Arc::clone(&col_a),
+            Operator::Plus,
+            Arc::clone(&col_c),
+            &schema,
+        )?;
+        assert!(group.normalize_expr_ref(&a_plus_c).is_none());
+
+        // Other members are replaced, also within larger expressions:
+        let normalized = group.normalize_expr_ref(&col_b).unwrap();
+        assert!(normalized.eq(&col_a));
+        let b_plus_c = binary(Arc::clone(&col_b), Operator::Plus, col_c, &schema)?;
+        let normalized = group.normalize_expr_ref(&b_plus_c).unwrap();
+        assert!(normalized.eq(&a_plus_c));
+        assert!(group.normalize_expr(b_plus_c).eq(&a_plus_c));
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_equalities() {
+        let c = |idx| Arc::new(Column::new(format!("col_{idx}").as_str(), idx)) as _;
+
+        let group = EquivalenceGroup::from_equalities([(c(0), c(1)), (c(1), c(2))]);
+        assert_eq!(group.len(), 1);
+        assert_eq!(group[0], EquivalenceClass::new([c(0), c(1), c(2)]));
+
+        // Disjoint equalities:
+        let pairs = [(c(0), c(1)), (c(2), c(3)), (c(3), c(2))];
+        let group = EquivalenceGroup::from_equalities(pairs.clone());
+        let mut expected = EquivalenceGroup::default();
+        for (left, right) in pairs {
+            expected.add_equal_conditions(left, right);
+        }
+        assert_eq!(group.len(), 2);
+        assert_eq!(group.classes, expected.classes);
+
+        // Trivial equalities are dropped:
+        let group = EquivalenceGroup::from_equalities([(c(4), c(4))]);
+        assert!(group.is_empty());
+        assert!(EquivalenceGroup::from_equalities([]).is_empty());
+        // Including literal ones, which would otherwise form a constant class:
+        let five = lit(5) as Arc<dyn PhysicalExpr>;
+        let group =
+            EquivalenceGroup::from_equalities([(Arc::clone(&five), Arc::clone(&five))]);
+        let mut expected = EquivalenceGroup::default();
+        expected.add_equal_conditions(Arc::clone(&five), five);
+        assert!(group.is_empty());
+        assert_eq!(group.classes, expected.classes);
+    }
+
+    #[test]
+    fn test_display_deterministic() {
+        let c = |idx| Arc::new(Column::new(format!("col_{idx}").as_str(), idx)) as _;
+
+        // Build the same group in two different ways:
+        let lhs = EquivalenceGroup::from_equalities([(c(2), c(0)), (c(1), c(2))]);
+        let mut rhs = EquivalenceGroup::default();
+        rhs.add_equal_conditions(c(0), c(1));
+        rhs.add_equal_conditions(c(1), c(2));
+        assert_eq!(lhs.classes, rhs.classes);
+        assert_eq!(lhs.to_string(), rhs.to_string());
+        assert_eq!(lhs.to_string(), "[{members: [col_0@0, col_1@1, col_2@2]}]");
+
+        assert_eq!(EquivalenceGroup::default().to_string(), "[]");
+    }
+
+    #[test]
+    fn test_singleton_classes_are_removed() {
+        let c = |idx| Arc::new(Column::new(format!("col_{idx}").as_str(), idx)) as _;
+
+        // Singleton classes are cleaned up instead of tripping the invariant
+        // checks, including ones overlapping with other classes:
+        let group = EquivalenceGroup::new([
+            EquivalenceClass::new([c(0), c(1)]),
+            EquivalenceClass::new([c(2)]),
+            EquivalenceClass::new([c(0)]),
+        ]);
+        assert_eq!(group.len(), 1);
+        assert_eq!(group[0], EquivalenceClass::new([c(0), c(1)]));
+        assert_eq!(group.equivalence_class(&c(0)), Some(&group[0]));
+        assert!(!group.contains_expr(&c(2)));
+
+        // Singleton classes of constants are kept:
+        let group = EquivalenceGroup::new([EquivalenceClass::new([lit(1)])]);
+        assert_eq!(group.len(), 1);
+
+        // Equalities of an expression with itself do not create classes:
+        let mut group = EquivalenceGroup::default();
+        assert!(!group.add_equal_conditions(c(0), c(0)));
+        group.add_equal_conditions_batch(&[(c(1), c(1))]);
+        assert!(group.is_empty());
+    }
+
+    #[test]
+    fn test_new_checked() {
+        let c = |idx| Arc::new(Column::new(format!("col_{idx}").as_str(), idx)) as _;
+
+        // Disjoint, non-trivial classes are already minimal:
+        let (group, changed) = EquivalenceGroup::new_checked([
+            EquivalenceClass::new([c(1), c(2)]),
+            EquivalenceClass::new([c(3), c(4)]),
+        ]);
+        assert!(!changed);
+        assert_eq!(group.len(), 2);
+
+        // Overlapping classes get merged:
+        let (group, changed) = EquivalenceGroup::new_checked([
+            EquivalenceClass::new([c(1), c(2)]),
+            EquivalenceClass::new([c(3), c(4)]),
+            EquivalenceClass::new([c(2), c(3)]),
+        ]);
+        assert!(changed);
+        assert_eq!(group.len(), 1);
+        assert_eq!(group[0], EquivalenceClass::new([c(1), c(2), c(3), c(4)]));
+
+        // Trivial classes get removed:
+        let (group, changed) = EquivalenceGroup::new_checked([
+            EquivalenceClass::new([c(1), c(2)]),
+            EquivalenceClass::new([c(3)]),
+        ]);
+        assert!(changed);
+        assert_eq!(group.len(), 1);
+    }
+
     #[test]
     fn test_remove_redundant_entries_eq_group() -> Result<()> {
         let c = |idx| Arc::new(Column::new(format!("col_{idx}").as_str(), idx)) as _;
@@ -1022,91 +3037,838 @@ mod tests {
         let col_c_expr = Arc::new(Column::new("c", 2)) as _;
 
         let cls1 = EquivalenceClass::new([Arc::clone(&lit_true), col_a_expr]);
-        let cls2 = EquivalenceClass::new([lit_true, col_b_expr]);
+        let cls2 = EquivalenceClass::new([Arc::clone(&lit_true), col_b_expr]);
         let cls3 = EquivalenceClass::new([col_c_expr, lit_false]);
 
         // lit_true is common
         assert!(cls1.contains_any(&cls2));
+        assert_eq!(cls1.common_expr(&cls2), Some(&lit_true));
         // there is no common entry
         assert!(!cls1.contains_any(&cls3));
         assert!(!cls2.contains_any(&cls3));
+        assert_eq!(cls1.common_expr(&cls3), None);
     }
 
     #[test]
-    fn test_exprs_equal() -> Result<()> {
-        struct TestCase {
-            left: Arc<dyn PhysicalExpr>,
-            right: Arc<dyn PhysicalExpr>,
-            expected: bool,
-            description: &'static str,
-        }
+    fn test_intersection_and_difference() {
+        let c = |idx| Arc::new(Column::new(&format!("c{idx}"), idx)) as _;
+        let cls =
+            |indices: &[usize]| EquivalenceClass::new(indices.iter().map(|i| c(*i)));
+        let order = |cls: &EquivalenceClass| cls.iter().cloned().collect::<Vec<_>>();
 
-        // Create test columns
-        let col_a = Arc::new(Column::new("a", 0)) as _;
+        // Disjoint classes:
+        let (lhs, rhs) = (cls(&[0, 1]), cls(&[2, 3]));
+        assert!(lhs.intersection(&rhs).is_empty());
+        assert_eq!(lhs.difference(&rhs), lhs);
+
+        // Overlapping classes, where results follow the order of `self`:
+        let (lhs, rhs) = (cls(&[3, 0, 1, 2]), cls(&[2, 4, 1]));
+        assert_eq!(order(&lhs.intersection(&rhs)), vec![c(1), c(2)]);
+        assert_eq!(order(&rhs.intersection(&lhs)), vec![c(2), c(1)]);
+        assert_eq!(order(&lhs.difference(&rhs)), vec![c(3), c(0)]);
+        assert_eq!(order(&rhs.difference(&lhs)), vec![c(4)]);
+
+        // Identical classes:
+        let lhs = cls(&[0, 1, 2]);
+        assert_eq!(lhs.intersection(&lhs), lhs);
+        assert!(lhs.difference(&lhs).is_empty());
+    }
+
+    #[test]
+    fn test_equivalence_class_hash() {
+        let c = |idx| Arc::new(Column::new(&format!("c{idx}"), idx)) as _;
+        let hash = |cls: &EquivalenceClass| {
+            let mut hasher = DefaultHasher::new();
+            cls.hash(&mut hasher);
+            hasher.finish()
+        };
+        let lhs = EquivalenceClass::new([c(0), c(1), c(2)]);
+        let rhs = EquivalenceClass::new([c(2), c(0), c(1)]);
+        assert_eq!(lhs, rhs);
+        assert_eq!(hash(&lhs), hash(&rhs));
+
+        let classes = [lhs.clone(), rhs, EquivalenceClass::new([c(0), c(1)])]
+            .into_iter()
+            .collect::<HashSet<_>>();
+        assert_eq!(classes.len(), 2);
+        assert!(classes.contains(&lhs));
+
+        // Constants take part in hashing like they do in equality:
+        let mut constant = lhs.clone();
+        constant.constant = Some(AcrossPartitions::Heterogeneous);
+        assert_ne!(constant, lhs);
+        assert_ne!(hash(&constant), hash(&lhs));
+    }
+
+    #[test]
+    fn test_remove_from_class() {
+        let col_a = Arc::new(Column::new("a", 0)) as Arc<dyn PhysicalExpr>;
         let col_b = Arc::new(Column::new("b", 1)) as _;
-        let col_x = Arc::new(Column::new("x", 2)) as _;
-        let col_y = Arc::new(Column::new("y", 3)) as _;
+        let col_c = Arc::new(Column::new("c", 2)) as _;
 
-        // Create test literals
-        let lit_1 = Arc::new(Literal::new(ScalarValue::from(1))) as _;
-        let lit_2 = Arc::new(Literal::new(ScalarValue::from(2))) as _;
+        let mut cls = EquivalenceClass::new([
+            Arc::clone(&col_a),
+            Arc::clone(&col_b),
+            Arc::clone(&col_c),
+        ]);
+        assert!(cls.remove(&col_a));
+        assert!(!cls.remove(&col_a));
+        assert_eq!(
+            cls,
+            EquivalenceClass::new([Arc::clone(&col_b), Arc::clone(&col_c)])
+        );
+        assert_eq!(cls.canonical_expr(), Some(&col_b));
 
-        // Create equivalence group with classes (a = x) and (b = y)
-        let eq_group = EquivalenceGroup::new([
-            EquivalenceClass::new([Arc::clone(&col_a), Arc::clone(&col_x)]),
-            EquivalenceClass::new([Arc::clone(&col_b), Arc::clone(&col_y)]),
+        // Removing down to a single member leaves a trivial class behind:
+        assert!(cls.remove(&col_b));
+        assert_eq!(cls.len(), 1);
+        assert!(cls.is_trivial());
+        assert_eq!(cls.canonical_expr(), Some(&col_c));
+
+        // Removing an earlier member keeps the canonical expression:
+        let mut cls = EquivalenceClass::new([
+            Arc::clone(&col_c),
+            Arc::clone(&col_b),
+            Arc::clone(&col_a),
         ]);
+        assert_eq!(cls.canonical_expr(), Some(&col_a));
+        assert!(cls.remove(&col_c));
+        assert_eq!(cls.canonical_expr(), Some(&col_a));
+    }
 
-        let test_cases = vec![
-            // Basic equality tests
-            TestCase {
-                left: Arc::clone(&col_a),
-                right: Arc::clone(&col_a),
-                expected: true,
-                description: "Same column should be equal",
-            },
-            // Equivalence class tests
-            TestCase {
-                left: Arc::clone(&col_a),
-                right: Arc::clone(&col_x),
-                expected: true,
-                description: "Columns in same equivalence class should be equal",
-            },
-            TestCase {
-                left: Arc::clone(&col_b),
-                right: Arc::clone(&col_y),
-                expected: true,
-                description: "Columns in same equivalence class should be equal",
-            },
-            TestCase {
-                left: Arc::clone(&col_a),
-                right: Arc::clone(&col_b),
-                expected: false,
-                description: "Columns in different equivalence classes should not be equal",
-            },
-            // Literal tests
-            TestCase {
-                left: Arc::clone(&lit_1),
-                right: Arc::clone(&lit_1),
-                expected: true,
-                description: "Same literal should be equal",
-            },
-            TestCase {
-                left: Arc::clone(&lit_1),
-                right: Arc::clone(&lit_2),
-                expected: false,
-                description: "Different literals should not be equal",
-            },
-            // Complex expression tests
-            TestCase {
-                left: Arc::new(BinaryExpr::new(
-                    Arc::clone(&col_a),
-                    Operator::Plus,
-                    Arc::clone(&col_b),
-                )) as _,
-                right: Arc::new(BinaryExpr::new(
-                    Arc::clone(&col_x),
-                    Operator::Plus,
+    /// An expression whose `Hash` implementation is inconsistent with its
+    /// `PartialEq` implementation, which ignores `salt`.
+    #[derive(Debug, Eq)]
+    struct InconsistentHashExpr {
+        name: &'static str,
+        salt: usize,
+    }
+
+    impl PartialEq for InconsistentHashExpr {
+        fn eq(&self, other: &Self) -> bool {
+            self.name == other.name
+        }
+    }
+
+    impl Hash for InconsistentHashExpr {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            self.name.hash(state);
+            self.salt.hash(state);
+        }
+    }
+
+    impl Display for InconsistentHashExpr {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            write!(f, "{}", self.name)
+        }
+    }
+
+    impl PhysicalExpr for InconsistentHashExpr {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn data_type(&self, _input_schema: &Schema) -> Result<DataType> {
+            Ok(DataType::Int32)
+        }
+
+        fn nullable(&self, _input_schema: &Schema) -> Result<bool> {
+            Ok(false)
+        }
+
+        fn evaluate(&self, _batch: &RecordBatch) -> Result<ColumnarValue> {
+            Ok(ColumnarValue::Scalar(ScalarValue::Int32(None)))
+        }
+
+        fn children(&self) -> Vec<&Arc<dyn PhysicalExpr>> {
+            vec![]
+        }
+
+        fn with_new_children(
+            self: Arc<Self>,
+            _children: Vec<Arc<dyn PhysicalExpr>>,
+        ) -> Result<Arc<dyn PhysicalExpr>> {
+            Ok(self)
+        }
+
+        fn fmt_sql(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", self.name)
+        }
+    }
+
+    #[test]
+    fn test_with_capacity() -> Result<()> {
+        let exprs = [
+            Arc::new(Column::new("a", 0)) as _,
+            Arc::new(Column::new("b", 1)) as _,
+            Arc::new(Column::new("a", 0)) as _,
+            lit(1),
+        ];
+        let mut cls = EquivalenceClass::with_capacity(16);
+        assert!(cls.is_empty());
+        for expr in exprs.iter().cloned() {
+            cls.push(expr);
+        }
+        let expected = EquivalenceClass::new(exprs);
+        assert_eq!(cls, expected);
+        assert_eq!(cls.len(), 3);
+        assert_eq!(
+            cls.iter().collect::<Vec<_>>(),
+            expected.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(cls.constant, expected.constant);
+        Ok(())
+    }
+
+    #[test]
+    fn test_dedup_structurally() {
+        let expr = |name, salt| {
+            Arc::new(InconsistentHashExpr { name, salt }) as Arc<dyn PhysicalExpr>
+        };
+        let col_a = Arc::new(Column::new("a", 0)) as Arc<dyn PhysicalExpr>;
+
+        let mut cls = EquivalenceClass::new([
+            expr("x", 0),
+            Arc::clone(&col_a),
+            expr("x", 1),
+            expr("y", 0),
+            expr("x", 2),
+            expr("y", 1),
+        ]);
+        // Hashing alone (usually) fails to detect the duplicates. Since hashes
+        // are randomized, they may occasionally collide and get detected:
+        assert!(cls.len() >= 3);
+
+        cls.dedup_structurally();
+        assert_eq!(cls.len(), 3);
+        let members = cls.iter().map(|e| e.to_string()).collect::<Vec<_>>();
+        assert_eq!(members, ["x", "a@0", "y"]);
+
+        // Classes without duplicates are left intact:
+        cls.dedup_structurally();
+        assert_eq!(cls.len(), 3);
+    }
+
+    #[test]
+    fn test_contains_expr() -> Result<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+            Field::new("c", DataType::Int32, false),
+        ]);
+        let col_a = col("a", &schema)?;
+        let col_b = col("b", &schema)?;
+        let col_c = col("c", &schema)?;
+        let mut group = EquivalenceGroup::default();
+        assert!(!group.contains_expr(&col_a));
+        group.add_equal_conditions(Arc::clone(&col_a), Arc::clone(&col_b));
+
+        assert!(group.contains_expr(&col_a));
+        assert!(group.contains_expr(&col_b));
+        assert!(!group.contains_expr(&col_c));
+        let expected = EquivalenceClass::new([Arc::clone(&col_a), Arc::clone(&col_b)]);
+        assert_eq!(group.equivalence_class(&col_b), Some(&expected));
+        assert_eq!(group.equivalence_class(&col_c), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_equal_conditions_checked() {
+        let c = |idx| Arc::new(Column::new(&format!("c{idx}"), idx)) as _;
+        let mut group = EquivalenceGroup::default();
+
+        // Creating a new class:
+        assert!(group.add_equal_conditions_checked(c(0), c(1)));
+        assert_eq!(group.len(), 1);
+        // Extending a class via the left and the right side:
+        assert!(group.add_equal_conditions_checked(c(1), c(2)));
+        assert!(group.add_equal_conditions_checked(c(3), c(0)));
+        assert_eq!(group.len(), 1);
+        assert_eq!(group[0].len(), 4);
+        // Merging two classes:
+        assert!(group.add_equal_conditions_checked(c(4), c(5)));
+        assert_eq!(group.len(), 2);
+        assert!(group.add_equal_conditions_checked(c(5), c(2)));
+        assert_eq!(group.len(), 1);
+        assert_eq!(group[0].len(), 6);
+        // Both sides are already in the same class:
+        assert!(!group.add_equal_conditions_checked(c(4), c(0)));
+        assert!(!group.add_equal_conditions_checked(c(3), c(3)));
+        assert_eq!(group.len(), 1);
+        assert_eq!(group[0].len(), 6);
+        // A trivial equality of an expression not in any class:
+        assert!(!group.add_equal_conditions_checked(c(7), c(7)));
+        assert_eq!(group.len(), 1);
+        assert_eq!(group[0].len(), 6);
+    }
+
+    #[test]
+    fn test_add_equal_conditions_batch() {
+        let c = |idx| Arc::new(Column::new(&format!("c{idx}"), idx)) as _;
+        let initial = [
+            EquivalenceClass::new([c(0), c(1)]),
+            EquivalenceClass::new([c(8), c(9)]),
+        ];
+        let pairs = vec![
+            (c(2), c(3)),
+            (c(4), c(5)),
+            // Bridges the first two pairs:
+            (c(3), c(4)),
+            // Extends an existing class:
+            (c(1), c(6)),
+            // Bridges an existing class with a new pair:
+            (c(5), c(9)),
+        ];
+
+        let mut sequential = EquivalenceGroup::new(initial.clone());
+        for (left, right) in pairs.iter().cloned() {
+            sequential.add_equal_conditions(left, right);
+        }
+        let mut batch = EquivalenceGroup::new(initial);
+        batch.add_equal_conditions_batch(&pairs);
+
+        assert_eq!(batch.len(), 2);
+        assert_eq!(batch.len(), sequential.len());
+        for cls in batch.iter() {
+            for expr in cls.iter() {
+                assert_eq!(batch.equivalence_class(expr), Some(cls));
+                assert_eq!(sequential.equivalence_class(expr), Some(cls));
+            }
+        }
+    }
+
+    #[test]
+    fn test_remove_expr_from_group() {
+        let c = |name| Arc::new(Column::new(name, 0)) as Arc<dyn PhysicalExpr>;
+        let mut group = EquivalenceGroup::new([
+            EquivalenceClass::new([c("a"), c("b"), c("c")]),
+            EquivalenceClass::new([c("x"), c("y")]),
+        ]);
+
+        group.remove_expr(&c("b"));
+        assert_eq!(
+            group.classes,
+            [
+                EquivalenceClass::new([c("a"), c("c")]),
+                EquivalenceClass::new([c("x"), c("y")]),
+            ]
+        );
+        assert!(group.equivalence_class(&c("b")).is_none());
+
+        // Removing an unknown expression is a no-op:
+        group.remove_expr(&c("z"));
+        assert_eq!(group.len(), 2);
+
+        // Classes that become trivial are removed entirely:
+        group.remove_expr(&c("x"));
+        assert_eq!(group.classes, [EquivalenceClass::new([c("a"), c("c")])]);
+        assert!(group.equivalence_class(&c("y")).is_none());
+    }
+
+    #[test]
+    fn test_add_equal_conditions_tagged() {
+        let col_a = Arc::new(Column::new("a", 0)) as Arc<dyn PhysicalExpr>;
+        let col_b = Arc::new(Column::new("b", 1)) as _;
+        let col_c = Arc::new(Column::new("c", 2)) as _;
+        let col_d = Arc::new(Column::new("d", 3)) as _;
+
+        let sources = |cls: &EquivalenceClass| {
+            cls.sources()
+                .iter()
+                .map(|source| source.to_string())
+                .collect::<Vec<_>>()
+        };
+
+        let mut tagged = EquivalenceGroup::default();
+        tagged.add_equal_conditions_tagged(
+            Arc::clone(&col_a),
+            Arc::clone(&col_b),
+            "filter: a = b",
+        );
+        tagged.add_equal_conditions_tagged(
+            Arc::clone(&col_c),
+            Arc::clone(&col_d),
+            "join: c = d",
+        );
+        assert_eq!(tagged.len(), 2);
+        assert_eq!(sources(&tagged[0]), ["filter: a = b"]);
+        assert_eq!(sources(&tagged[1]), ["join: c = d"]);
+
+        // Bridging classes merges their sources, which can also be formatted
+        // at runtime:
+        tagged.add_equal_conditions_tagged(
+            Arc::clone(&col_b),
+            Arc::clone(&col_c),
+            format!(
+                "filter: {} = {}",
+                fmt_sql(col_b.as_ref()),
+                fmt_sql(col_c.as_ref())
+            ),
+        );
+        assert_eq!(tagged.len(), 1);
+        assert_eq!(
+            sources(&tagged[0]),
+            ["filter: a = b", "join: c = d", "filter: b = c"]
+        );
+
+        // Sources are only displayed in the alternate form:
+        assert_eq!(tagged.to_string(), "[{members: [a@0, b@1, c@2, d@3]}]");
+        assert_eq!(
+            format!("{tagged:#}"),
+            "[{members: [a@0, b@1, c@2, d@3], sources: [filter: a = b, join: c = d, filter: b = c]}]"
+        );
+
+        // Sources do not affect equality:
+        let mut untagged = EquivalenceGroup::default();
+        untagged.add_equal_conditions(col_a, Arc::clone(&col_b));
+        untagged.add_equal_conditions(Arc::clone(&col_c), col_d);
+        untagged.add_equal_conditions(col_b, col_c);
+        assert!(untagged[0].sources().is_empty());
+        assert_eq!(tagged[0], untagged[0]);
+    }
+
+    #[test]
+    fn test_canonical_expr_lowest_cost() {
+        let col_a = Arc::new(Column::new("a", 0)) as Arc<dyn PhysicalExpr>;
+        let col_b = Arc::new(Column::new("b", 1)) as _;
+        let col_c = Arc::new(Column::new("c", 2)) as _;
+        let a_plus_b = Arc::new(BinaryExpr::new(
+            Arc::clone(&col_a),
+            Operator::Plus,
+            Arc::clone(&col_b),
+        )) as Arc<dyn PhysicalExpr>;
+        let a_plus_b_times_c = Arc::new(BinaryExpr::new(
+            Arc::clone(&a_plus_b),
+            Operator::Multiply,
+            Arc::clone(&col_c),
+        )) as Arc<dyn PhysicalExpr>;
+        assert_eq!(col_a.cost(), 1);
+        assert_eq!(a_plus_b.cost(), 4);
+        assert_eq!(a_plus_b_times_c.cost(), 7);
+        let one = lit(1);
+
+        let test_cases = [
+            // A column is preferred over a complex expression inserted earlier:
+            (vec![Arc::clone(&a_plus_b), Arc::clone(&col_c)], &col_c),
+            // The shallower of two complex expressions is preferred:
+            (
+                vec![Arc::clone(&a_plus_b_times_c), Arc::clone(&a_plus_b)],
+                &a_plus_b,
+            ),
+            // Among columns, the lowest index is preferred:
+            (vec![Arc::clone(&col_b), Arc::clone(&col_a)], &col_a),
+            (
+                vec![
+                    Arc::clone(&a_plus_b_times_c),
+                    Arc::clone(&col_c),
+                    Arc::clone(&col_a),
+                ],
+                &col_a,
+            ),
+            // Remaining ties are broken by insertion order:
+            (vec![Arc::clone(&one), Arc::clone(&col_a)], &one),
+            (vec![Arc::clone(&col_c), Arc::clone(&one)], &col_c),
+        ];
+        for (exprs, expected) in test_cases {
+            let cls = EquivalenceClass::new(exprs);
+            assert_eq!(cls.canonical_expr(), Some(expected), "class: {cls}");
+        }
+        assert!(EquivalenceClass::default().canonical_expr().is_none());
+
+        // Groups normalize all members of a class to its canonical expression:
+        let cls = EquivalenceClass::new([
+            Arc::clone(&one),
+            Arc::clone(&col_c),
+            Arc::clone(&col_a),
+        ]);
+        let group = EquivalenceGroup::new([cls.clone()]);
+        for expr in cls.iter() {
+            let normalized = group.normalize_expr(Arc::clone(expr));
+            assert_eq!(Some(&normalized), cls.canonical_expr());
+        }
+    }
+
+    /// A leaf expression that reports a fixed cost, overriding the default.
+    #[derive(Debug, PartialEq, Eq, Hash)]
+    struct FixedCostExpr {
+        name: &'static str,
+        cost: u64,
+    }
+
+    impl Display for FixedCostExpr {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            write!(f, "{}", self.name)
+        }
+    }
+
+    impl PhysicalExpr for FixedCostExpr {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn data_type(&self, _input_schema: &Schema) -> Result<DataType> {
+            Ok(DataType::Int32)
+        }
+
+        fn nullable(&self, _input_schema: &Schema) -> Result<bool> {
+            Ok(false)
+        }
+
+        fn evaluate(&self, _batch: &RecordBatch) -> Result<ColumnarValue> {
+            Ok(ColumnarValue::Scalar(ScalarValue::Int32(None)))
+        }
+
+        fn children(&self) -> Vec<&Arc<dyn PhysicalExpr>> {
+            vec![]
+        }
+
+        fn with_new_children(
+            self: Arc<Self>,
+            _children: Vec<Arc<dyn PhysicalExpr>>,
+        ) -> Result<Arc<dyn PhysicalExpr>> {
+            Ok(self)
+        }
+
+        fn fmt_sql(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", self.name)
+        }
+
+        fn cost(&self) -> u64 {
+            self.cost
+        }
+    }
+
+    #[test]
+    fn test_canonical_expr_custom_cost() {
+        let expr =
+            |name, cost| Arc::new(FixedCostExpr { name, cost }) as Arc<dyn PhysicalExpr>;
+        let col_a = Arc::new(Column::new("a", 0)) as Arc<dyn PhysicalExpr>;
+        let a_plus_b = Arc::new(BinaryExpr::new(
+            Arc::clone(&col_a),
+            Operator::Plus,
+            Arc::new(Column::new("b", 1)),
+        )) as Arc<dyn PhysicalExpr>;
+
+        // The cost of parent expressions includes overridden child costs:
+        let expensive = expr("expensive", 100);
+        let sum = Arc::new(BinaryExpr::new(
+            Arc::clone(&expensive),
+            Operator::Plus,
+            Arc::clone(&col_a),
+        )) as Arc<dyn PhysicalExpr>;
+        assert_eq!(sum.cost(), 103);
+
+        // An expensive leaf loses to a composite expression:
+        let cls = EquivalenceClass::new([Arc::clone(&expensive), Arc::clone(&a_plus_b)]);
+        assert_eq!(cls.canonical_expr(), Some(&a_plus_b));
+        // A leaf as cheap as a column ties with it, so insertion order decides:
+        let cheap = expr("cheap", 1);
+        let cls = EquivalenceClass::new([Arc::clone(&cheap), Arc::clone(&col_a)]);
+        assert_eq!(cls.canonical_expr(), Some(&cheap));
+    }
+
+    #[test]
+    fn test_try_with_offset_sub() -> Result<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+        ]);
+        let col_a = col("a", &schema)?;
+        let col_b = col("b", &schema)?;
+        let a_plus_b = binary(Arc::clone(&col_a), Operator::Plus, col_b, &schema)?;
+        let cls = EquivalenceClass::new([col_a, a_plus_b, lit(1)]);
+
+        let shifted = cls.try_with_offset(5)?;
+        assert_ne!(shifted, cls);
+        assert_eq!(shifted.try_with_offset_sub(5), Some(cls.clone()));
+        assert_eq!(
+            shifted.try_with_offset_sub(2),
+            Some(cls.try_with_offset(3)?)
+        );
+        // Column "a" is at index 5, so we can not subtract more than that:
+        assert_eq!(shifted.try_with_offset_sub(6), None);
+        assert_eq!(cls.try_with_offset_sub(1), None);
+        assert_eq!(cls.try_with_offset_sub(0), Some(cls));
+        Ok(())
+    }
+
+    #[test]
+    fn test_canonical_expr_skips_volatile() -> Result<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+        ]);
+        let col_a = col("a", &schema)?;
+        let a_plus_b = binary(
+            Arc::clone(&col_a),
+            Operator::Plus,
+            col("b", &schema)?,
+            &schema,
+        )?;
+        let random = || -> Result<Arc<dyn PhysicalExpr>> {
+            Ok(Arc::new(ScalarFunctionExpr::try_new(
+                datafusion_functions::math::random(),
+                vec![],
+                &schema,
+                Arc::new(ConfigOptions::default()),
+            )?))
+        };
+        let random_alias = random()?;
+        assert!(is_volatile(&random_alias));
+        assert!(random_alias.cost() < a_plus_b.cost());
+
+        let cls = EquivalenceClass::new([Arc::clone(&random_alias), Arc::clone(&col_a)]);
+        assert_eq!(cls.canonical_expr(), Some(&col_a));
+        // A deterministic member is preferred even if it is more expensive:
+        let cls =
+            EquivalenceClass::new([Arc::clone(&random_alias), Arc::clone(&a_plus_b)]);
+        assert_eq!(cls.canonical_expr(), Some(&a_plus_b));
+        // Normalization never introduces the volatile expression:
+        let group = EquivalenceGroup::new([cls]);
+        assert!(group.normalize_expr(Arc::clone(&a_plus_b)).eq(&a_plus_b));
+        // Classes without deterministic members still have a canonical member:
+        let volatile_plus_a = binary(random()?, Operator::Plus, col_a, &schema)?;
+        let cls = EquivalenceClass::new([
+            Arc::clone(&volatile_plus_a),
+            Arc::clone(&random_alias),
+        ]);
+        assert_eq!(cls.canonical_expr(), Some(&random_alias));
+        Ok(())
+    }
+
+    #[test]
+    fn test_canonical_expr_with_offset() -> Result<()> {
+        let col_a = Arc::new(Column::new("a", 0)) as Arc<dyn PhysicalExpr>;
+        let col_b = Arc::new(Column::new("b", 1)) as _;
+        let col_c = Arc::new(Column::new("c", 2)) as _;
+        let a_plus_b = Arc::new(BinaryExpr::new(
+            Arc::clone(&col_a),
+            Operator::Plus,
+            Arc::clone(&col_b),
+        )) as Arc<dyn PhysicalExpr>;
+        let one = lit(1);
+
+        let test_cases = [
+            // The canonical expression is the second inserted member:
+            (vec![Arc::clone(&a_plus_b), Arc::clone(&col_c)], &col_c),
+            (vec![Arc::clone(&col_b), Arc::clone(&col_a)], &col_a),
+            // Ties broken by insertion order must survive the offset:
+            (vec![Arc::clone(&one), Arc::clone(&col_b)], &one),
+            (vec![Arc::clone(&col_c), Arc::clone(&one)], &col_c),
+        ];
+        for (exprs, canonical) in test_cases {
+            let cls = EquivalenceClass::new(exprs);
+            assert_eq!(cls.canonical_expr(), Some(canonical), "class: {cls}");
+            let expected = add_offset_to_expr(Arc::clone(canonical), 3)?;
+            let offset_cls = cls.try_with_offset(3)?;
+            assert_eq!(
+                offset_cls.canonical_expr(),
+                Some(&expected),
+                "class: {offset_cls}"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_union() -> Result<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+            Field::new("c", DataType::Int32, false),
+            Field::new("d", DataType::Int32, false),
+            Field::new("e", DataType::Int32, false),
+        ]);
+        let [col_a, col_b, col_c, col_d, col_e] =
+            ["a", "b", "c", "d", "e"].map(|name| col(name, &schema).unwrap());
+        let lhs = EquivalenceGroup::new([EquivalenceClass::new([
+            Arc::clone(&col_a),
+            Arc::clone(&col_b),
+        ])]);
+        let rhs = EquivalenceGroup::new([
+            EquivalenceClass::new([Arc::clone(&col_b), Arc::clone(&col_c)]),
+            EquivalenceClass::new([Arc::clone(&col_d), Arc::clone(&col_e)]),
+        ]);
+
+        let union = lhs.union(&rhs);
+        assert_eq!(union.len(), 2);
+        assert_eq!(
+            union[0],
+            EquivalenceClass::new([Arc::clone(&col_a), col_b, Arc::clone(&col_c)])
+        );
+        assert_eq!(union[1], EquivalenceClass::new([col_d, col_e]));
+        assert!(union.exprs_equal(&col_a, &col_c));
+        assert_eq!(lhs.union(&EquivalenceGroup::default()).classes, lhs.classes);
+        Ok(())
+    }
+
+    #[test]
+    fn test_intersect() -> Result<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+            Field::new("c", DataType::Int32, false),
+            Field::new("d", DataType::Int32, false),
+            Field::new("e", DataType::Int32, false),
+        ]);
+        let col_a = col("a", &schema)?;
+        let col_b = col("b", &schema)?;
+        let col_c = col("c", &schema)?;
+        let col_d = col("d", &schema)?;
+        let col_e = col("e", &schema)?;
+
+        // Left input of the union has a = b = c and d = e, right input has
+        // a = b and c = d = e:
+        let lhs = EquivalenceGroup::new([
+            EquivalenceClass::new([
+                Arc::clone(&col_a),
+                Arc::clone(&col_b),
+                Arc::clone(&col_c),
+            ]),
+            EquivalenceClass::new([Arc::clone(&col_d), Arc::clone(&col_e)]),
+        ]);
+        let rhs = EquivalenceGroup::new([
+            EquivalenceClass::new([Arc::clone(&col_a), Arc::clone(&col_b)]),
+            EquivalenceClass::new([
+                Arc::clone(&col_c),
+                Arc::clone(&col_d),
+                Arc::clone(&col_e),
+            ]),
+        ]);
+        let expected = vec![
+            EquivalenceClass::new([Arc::clone(&col_a), Arc::clone(&col_b)]),
+            EquivalenceClass::new([Arc::clone(&col_d), Arc::clone(&col_e)]),
+        ];
+        assert_eq!(lhs.intersect(&rhs).classes, expected);
+        assert_eq!(rhs.intersect(&lhs).classes, expected);
+
+        // Intersecting with itself or with an empty group:
+        assert_eq!(lhs.intersect(&lhs).classes, lhs.classes);
+        assert!(lhs.intersect(&EquivalenceGroup::default()).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_join_nullable_side_classes() -> Result<()> {
+        let c = |idx| Arc::new(Column::new(&format!("c{idx}"), idx)) as _;
+        // Left side has a = b (columns 0 and 1), right side has x = y
+        // (columns 0 and 1, i.e. 2 and 3 after the join):
+        let left = EquivalenceGroup::new([EquivalenceClass::new([c(0), c(1)])]);
+        let right = EquivalenceGroup::new([EquivalenceClass::new([c(0), c(1)])]);
+        let left_class = EquivalenceClass::new([c(0), c(1)]);
+        let right_class = right[0].try_with_offset(2)?;
+
+        // Null padding keeps equalities between columns of the same side:
+        for join_type in [
+            JoinType::Inner,
+            JoinType::Left,
+            JoinType::Right,
+            JoinType::Full,
+        ] {
+            let joined = left.join(&right, &join_type, 2, &[])?;
+            assert_eq!(
+                joined.iter().collect::<Vec<_>>(),
+                vec![&left_class, &right_class],
+                "join type: {join_type}"
+            );
+        }
+
+        // Nullable side classes lose their constants and non-column members:
+        let mut right = right.clone();
+        right.add_equal_conditions(c(1), lit(5));
+        let joined = left.join(&right, &JoinType::Left, 2, &[])?;
+        assert_eq!(joined.len(), 2);
+        assert_eq!(joined[1], right_class);
+        assert!(joined[1].constant().is_none());
+        assert!(!joined.contains_expr(&lit(5)));
+        // Whereas preserved side classes keep them:
+        let joined = left.join(&right, &JoinType::Right, 2, &[])?;
+        assert_eq!(joined.len(), 2);
+        assert!(joined[1].constant().is_some());
+        assert!(joined.contains_expr(&lit(5)));
+
+        // Classes left with a single column are dropped:
+        let right = EquivalenceGroup::new([EquivalenceClass::new([c(0), lit(5)])]);
+        let joined = left.join(&right, &JoinType::Full, 2, &[])?;
+        assert_eq!(joined.iter().collect::<Vec<_>>(), vec![&left_class]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_exprs_equal() -> Result<()> {
+        struct TestCase {
+            left: Arc<dyn PhysicalExpr>,
+            right: Arc<dyn PhysicalExpr>,
+            expected: bool,
+            description: &'static str,
+        }
+
+        // Create test columns
+        let col_a = Arc::new(Column::new("a", 0)) as _;
+        let col_b = Arc::new(Column::new("b", 1)) as _;
+        let col_x = Arc::new(Column::new("x", 2)) as _;
+        let col_y = Arc::new(Column::new("y", 3)) as _;
+
+        // Create test literals
+        let lit_1 = Arc::new(Literal::new(ScalarValue::from(1))) as _;
+        let lit_2 = Arc::new(Literal::new(ScalarValue::from(2))) as _;
+
+        // Create equivalence group with classes (a = x) and (b = y)
+        let eq_group = EquivalenceGroup::new([
+            EquivalenceClass::new([Arc::clone(&col_a), Arc::clone(&col_x)]),
+            EquivalenceClass::new([Arc::clone(&col_b), Arc::clone(&col_y)]),
+        ]);
+
+        let test_cases = vec![
+            // Basic equality tests
+            TestCase {
+                left: Arc::clone(&col_a),
+                right: Arc::clone(&col_a),
+                expected: true,
+                description: "Same column should be equal",
+            },
+            // Equivalence class tests
+            TestCase {
+                left: Arc::clone(&col_a),
+                right: Arc::clone(&col_x),
+                expected: true,
+                description: "Columns in same equivalence class should be equal",
+            },
+            TestCase {
+                left: Arc::clone(&col_b),
+                right: Arc::clone(&col_y),
+                expected: true,
+                description: "Columns in same equivalence class should be equal",
+            },
+            TestCase {
+                left: Arc::clone(&col_a),
+                right: Arc::clone(&col_b),
+                expected: false,
+                description: "Columns in different equivalence classes should not be equal",
+            },
+            // Literal tests
+            TestCase {
+                left: Arc::clone(&lit_1),
+                right: Arc::clone(&lit_1),
+                expected: true,
+                description: "Same literal should be equal",
+            },
+            TestCase {
+                left: Arc::clone(&lit_1),
+                right: Arc::clone(&lit_2),
+                expected: false,
+                description: "Different literals should not be equal",
+            },
+            // Complex expression tests
+            TestCase {
+                left: Arc::new(BinaryExpr::new(
+                    Arc::clone(&col_a),
+                    Operator::Plus,
+                    Arc::clone(&col_b),
+                )) as _,
+                right: Arc::new(BinaryExpr::new(
+                    Arc::clone(&col_x),
+                    Operator::Plus,
                     Arc::clone(&col_y),
                 )) as _,
                 expected: true,
@@ -1154,33 +3916,630 @@ mod tests {
                     Arc::new(BinaryExpr::new(
                         Arc::clone(&col_x),
                         Operator::Plus,
-                        Arc::clone(&col_y),
-                    )),
-                    Operator::Multiply,
-                    Arc::clone(&lit_1),
-                )) as _,
-                expected: true,
-                description: "Nested binary expressions with equivalent operands should be equal",
-            },
+                        Arc::clone(&col_y),
+                    )),
+                    Operator::Multiply,
+                    Arc::clone(&lit_1),
+                )) as _,
+                expected: true,
+                description: "Nested binary expressions with equivalent operands should be equal",
+            },
+        ];
+
+        for TestCase {
+            left,
+            right,
+            expected,
+            description,
+        } in test_cases
+        {
+            let actual = eq_group.exprs_equal(&left, &right);
+            assert_eq!(
+                actual, expected,
+                "{description}: Failed comparing {left:?} and {right:?}, expected {expected}, got {actual}"
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_class_from_iter() {
+        let class: EquivalenceClass = [lit(1), lit(2), lit(1), lit(3), lit(2)]
+            .into_iter()
+            .collect();
+        assert_eq!(class.len(), 3);
+        assert_eq!(class, EquivalenceClass::new([lit(1), lit(2), lit(3)]));
+    }
+
+    #[test]
+    fn test_class_extend_from_iter() {
+        let mut class = EquivalenceClass::new([lit(1), lit(2)]);
+        class.extend_from_iter(vec![lit(2), lit(3), lit(3)]);
+        assert_eq!(class.len(), 3);
+        assert_eq!(class, EquivalenceClass::new([lit(1), lit(2), lit(3)]));
+
+        // Literals make the class constant like with `push`:
+        let col_a = Arc::new(Column::new("a", 0)) as _;
+        let mut class = EquivalenceClass::new([col_a]);
+        assert!(class.constant().is_none());
+        class.extend_from_iter(vec![lit(5)]);
+        assert_eq!(
+            class.constant(),
+            Some(&AcrossPartitions::Uniform(Some(ScalarValue::Int32(Some(
+                5
+            )))))
+        );
+    }
+
+    #[test]
+    fn test_class_map() {
+        let c = |idx| Arc::new(Column::new(format!("col_{idx}").as_str(), idx)) as _;
+        let index = |expr: &Arc<dyn PhysicalExpr>| {
+            expr.as_any().downcast_ref::<Column>().unwrap().index()
+        };
+        let cls = EquivalenceClass::new([c(0), c(1), c(2)])
+            .with_constant(AcrossPartitions::Heterogeneous);
+
+        // Shift all column indices by 3:
+        let shifted = cls.map(|expr| c(index(expr) + 3));
+        assert_eq!(shifted.sorted_exprs(), [c(3), c(4), c(5)]);
+        assert_eq!(shifted.constant, Some(AcrossPartitions::Heterogeneous));
+
+        // Members that map to the same expression are deduplicated:
+        let halved = cls.map(|expr| c(index(expr) / 2));
+        assert_eq!(halved.sorted_exprs(), [c(0), c(1)]);
+    }
+
+    #[test]
+    fn test_class_sorted_exprs() {
+        let c = |idx| Arc::new(Column::new(format!("col_{idx}").as_str(), idx)) as _;
+        let lhs = EquivalenceClass::new([c(2), lit(1), c(0)]);
+        let rhs = EquivalenceClass::new([c(0), c(2), lit(1)]);
+        assert_eq!(lhs.sorted_exprs(), rhs.sorted_exprs());
+        assert_eq!(lhs.sorted_exprs(), [lit(1), c(0), c(2)]);
+        assert!(EquivalenceClass::default().sorted_exprs().is_empty());
+    }
+
+    #[test]
+    fn test_simplify_expr() -> Result<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+            Field::new("c", DataType::Int32, false),
+        ]);
+        let col_a = col("a", &schema)?;
+        let col_b = col("b", &schema)?;
+        let col_c = col("c", &schema)?;
+        let eq_group = EquivalenceGroup::new([EquivalenceClass::new([
+            Arc::clone(&col_a),
+            Arc::clone(&col_b),
+        ])]);
+        let constants = [ConstExpr::uniform(
+            Arc::clone(&col_b),
+            ScalarValue::Int32(Some(5)),
+        )];
+
+        // `a` is equivalent to the constant `b`:
+        let expr = binary(Arc::clone(&col_a), Operator::Plus, lit(1), &schema)?;
+        let simplified = eq_group.simplify_expr(expr, &constants);
+        assert_eq!(simplified.to_string(), "5 + 1");
+
+        // `c` is not known to be constant, so it is left as is:
+        let expr = binary(Arc::clone(&col_c), Operator::Plus, col_b, &schema)?;
+        let simplified = eq_group.simplify_expr(expr, &constants);
+        assert_eq!(simplified.to_string(), "c@2 + 5");
+
+        // Without any constants, only normalization takes place:
+        let simplified = eq_group.simplify_expr(Arc::clone(&col_a), &[]);
+        assert!(simplified.eq(&col_a));
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialize_group() -> Result<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+            Field::new("c", DataType::Int32, false),
+        ]);
+        let col_a = col("a", &schema)?;
+        let col_b = col("b", &schema)?;
+        let col_c = col("c", &schema)?;
+        let mut group = EquivalenceGroup::default();
+        group.add_equal_conditions_tagged(Arc::clone(&col_a), col_b, "a = b");
+        group.add_constant(ConstExpr::uniform(col_c, ScalarValue::Int32(Some(5))));
+
+        let json = serde_json::to_string(&group).unwrap();
+        assert_eq!(
+            json,
+            r#"{"classes":[{"exprs":["a@0","b@1"],"constant":null,"sources":["a = b"]},{"exprs":["c@2"],"constant":{"Uniform":"5"},"sources":[]}]}"#
+        );
+
+        let const_expr = ConstExpr::new(
+            col_a,
+            PartitionValues::Heterogeneous(vec![Some(ScalarValue::Int32(Some(1))), None]),
+        );
+        let json = serde_json::to_string(&const_expr).unwrap();
+        assert_eq!(
+            json,
+            r#"{"expr":"a@0","across_partitions":{"Heterogeneous":["1",null]}}"#
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut eq_group = EquivalenceGroup::new([
+            EquivalenceClass::new([lit(1), lit(2)]),
+            EquivalenceClass::new([lit(3), lit(4), lit(5)]),
+        ]);
+        assert_eq!(eq_group.total_exprs(), 5);
+
+        eq_group.clear();
+        assert!(eq_group.is_empty());
+        assert_eq!(eq_group.total_exprs(), 0);
+        assert!(!eq_group.contains_expr(&lit(1)));
+    }
+
+    #[test]
+    fn test_canonical_form() -> Result<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+            Field::new("c", DataType::Int32, false),
+            Field::new("d", DataType::Int32, false),
+        ]);
+        let [col_a, col_b, col_c, col_d] =
+            ["a", "b", "c", "d"].map(|name| col(name, &schema).unwrap());
+
+        let mut lhs = EquivalenceGroup::default();
+        lhs.add_equal_conditions(Arc::clone(&col_a), Arc::clone(&col_b));
+        lhs.add_equal_conditions(Arc::clone(&col_c), Arc::clone(&col_d));
+        lhs.add_constant(ConstExpr::from(lit(1)));
+        let mut rhs = EquivalenceGroup::default();
+        rhs.add_constant(ConstExpr::from(lit(1)));
+        rhs.add_equal_conditions(Arc::clone(&col_d), Arc::clone(&col_c));
+        rhs.add_equal_conditions(Arc::clone(&col_b), Arc::clone(&col_a));
+
+        // Display follows the insertion order of classes:
+        assert_ne!(lhs.to_string(), rhs.to_string());
+        assert_eq!(lhs.canonical_form(), rhs.canonical_form());
+        assert_eq!(
+            lhs.canonical_form(),
+            "[{members: [1], constant: (uniform: 1)}, {members: [a@0, b@1]}, {members: [c@2, d@3]}]"
+        );
+        assert_eq!(EquivalenceGroup::default().canonical_form(), "[]");
+        Ok(())
+    }
+
+    #[test]
+    fn test_expr_class_pairs() {
+        let eq_group = EquivalenceGroup::new([
+            EquivalenceClass::new([lit(1), lit(2)]),
+            EquivalenceClass::new([lit(3), lit(4), lit(5)]),
+        ]);
+        let pairs = eq_group.expr_class_pairs().collect::<Vec<_>>();
+        assert_eq!(pairs.len(), eq_group.total_exprs());
+        for (expr, idx) in pairs {
+            assert_eq!(eq_group.equivalence_class(expr), Some(&eq_group[idx]));
+        }
+        assert_eq!(EquivalenceGroup::default().expr_class_pairs().count(), 0);
+    }
+
+    #[test]
+    fn test_retain_classes() {
+        let c = |idx| Arc::new(Column::new(format!("col_{idx}").as_str(), idx)) as _;
+        let mut eq_group = EquivalenceGroup::new([
+            EquivalenceClass::new([c(0), c(1)]),
+            EquivalenceClass::new([c(2), c(3)]),
+            EquivalenceClass::new([c(4), c(1), c(5)]),
+            EquivalenceClass::new([c(6), c(7)]),
+        ]);
+        assert_eq!(eq_group.len(), 3);
+
+        // Keep only the classes that mention `col_7` or `col_3`:
+        eq_group.retain_classes(|cls| cls.contains(&c(7)) || cls.contains(&c(3)));
+        assert_eq!(eq_group.len(), 2);
+        assert_eq!(eq_group[0], EquivalenceClass::new([c(2), c(3)]));
+        assert_eq!(eq_group[1], EquivalenceClass::new([c(6), c(7)]));
+        assert!(!eq_group.contains_expr(&c(0)));
+        assert_eq!(eq_group.equivalence_class(&c(6)), Some(&eq_group[1]));
+
+        eq_group.retain_classes(|_| false);
+        assert!(eq_group.is_empty());
+        assert_eq!(eq_group.total_exprs(), 0);
+    }
+
+    #[test]
+    fn test_remove_class() {
+        let mut eq_group = EquivalenceGroup::new([
+            EquivalenceClass::new([lit(1), lit(2)]),
+            EquivalenceClass::new([lit(3), lit(4)]),
+            EquivalenceClass::new([lit(5), lit(6), lit(7)]),
+        ]);
+        assert!(eq_group.remove_class(3).is_none());
+        assert_eq!(eq_group.len(), 3);
+
+        let removed = eq_group.remove_class(1).unwrap();
+        assert_eq!(removed, EquivalenceClass::new([lit(3), lit(4)]));
+        assert_eq!(eq_group.len(), 2);
+        assert_eq!(eq_group.total_exprs(), 5);
+        assert!(!eq_group.contains_expr(&lit(3)));
+        // The last class takes the place of the removed one:
+        assert_eq!(eq_group[1], EquivalenceClass::new([lit(5), lit(6), lit(7)]));
+        for expr in [lit(5), lit(6), lit(7)] {
+            assert_eq!(eq_group.equivalence_class(&expr), Some(&eq_group[1]));
+        }
+        assert!(eq_group.normalize_expr(lit(2)).eq(&lit(1)));
+    }
+
+    /// An expression that is not equal to any expression, not even itself,
+    /// so that only pointer comparisons can identify it.
+    #[derive(Debug, Eq)]
+    struct NeverEqualExpr;
+
+    impl PartialEq for NeverEqualExpr {
+        fn eq(&self, _other: &Self) -> bool {
+            false
+        }
+    }
+
+    impl Hash for NeverEqualExpr {
+        fn hash<H: Hasher>(&self, _state: &mut H) {}
+    }
+
+    impl Display for NeverEqualExpr {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            write!(f, "never_equal")
+        }
+    }
+
+    impl PhysicalExpr for NeverEqualExpr {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+
+        fn data_type(&self, _input_schema: &Schema) -> Result<DataType> {
+            Ok(DataType::Int32)
+        }
+
+        fn nullable(&self, _input_schema: &Schema) -> Result<bool> {
+            Ok(false)
+        }
+
+        fn evaluate(&self, _batch: &RecordBatch) -> Result<ColumnarValue> {
+            Ok(ColumnarValue::Scalar(ScalarValue::Int32(None)))
+        }
+
+        fn children(&self) -> Vec<&Arc<dyn PhysicalExpr>> {
+            vec![]
+        }
+
+        fn with_new_children(
+            self: Arc<Self>,
+            _children: Vec<Arc<dyn PhysicalExpr>>,
+        ) -> Result<Arc<dyn PhysicalExpr>> {
+            Ok(self)
+        }
+
+        fn fmt_sql(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "never_equal")
+        }
+    }
+
+    #[test]
+    fn test_exprs_equal_same_arc() -> Result<()> {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
+        let group = EquivalenceGroup::default();
+        let expr = Arc::new(NeverEqualExpr) as Arc<dyn PhysicalExpr>;
+
+        // Clones of the same `Arc` are equal without comparing structurally:
+        assert!(group.exprs_equal(&expr, &Arc::clone(&expr)));
+        assert!(group.exprs_equal_associative(&expr, &Arc::clone(&expr), &schema));
+        let sum = binary(
+            Arc::clone(&expr),
+            Operator::Plus,
+            col("a", &schema)?,
+            &schema,
+        )?;
+        assert!(group.exprs_equal(&sum, &Arc::clone(&sum)));
+
+        // Separate instances are still compared structurally:
+        assert!(!group.exprs_equal(&expr, &(Arc::new(NeverEqualExpr) as _)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_exprs_equal_associative() -> Result<()> {
+        let schema = Schema::new(
+            ["a", "b", "c", "d", "x"]
+                .map(|name| Field::new(name, DataType::Int64, false))
+                .to_vec(),
+        );
+        let col_a = Arc::new(Column::new("a", 0)) as _;
+        let col_b = Arc::new(Column::new("b", 1)) as _;
+        let col_c = Arc::new(Column::new("c", 2)) as _;
+        let col_d = Arc::new(Column::new("d", 3)) as _;
+        let col_x = Arc::new(Column::new("x", 4)) as _;
+
+        // Create equivalence group with class (a = x)
+        let eq_group = EquivalenceGroup::new([EquivalenceClass::new([
+            Arc::clone(&col_a),
+            Arc::clone(&col_x),
+        ])]);
+        let binary = |l: &Arc<dyn PhysicalExpr>, op, r: &Arc<dyn PhysicalExpr>| {
+            Arc::new(BinaryExpr::new(Arc::clone(l), op, Arc::clone(r)))
+                as Arc<dyn PhysicalExpr>
+        };
+
+        let test_cases = [
+            // (a + b) + c vs a + (b + c)
+            (
+                binary(
+                    &binary(&col_a, Operator::Plus, &col_b),
+                    Operator::Plus,
+                    &col_c,
+                ),
+                binary(
+                    &col_a,
+                    Operator::Plus,
+                    &binary(&col_b, Operator::Plus, &col_c),
+                ),
+                true,
+            ),
+            // (a * b) * c vs x * (b * c), where a = x
+            (
+                binary(
+                    &binary(&col_a, Operator::Multiply, &col_b),
+                    Operator::Multiply,
+                    &col_c,
+                ),
+                binary(
+                    &col_x,
+                    Operator::Multiply,
+                    &binary(&col_b, Operator::Multiply, &col_c),
+                ),
+                true,
+            ),
+            // ((a + b) + c) + d vs (a + b) + (c + d)
+            (
+                binary(
+                    &binary(
+                        &binary(&col_a, Operator::Plus, &col_b),
+                        Operator::Plus,
+                        &col_c,
+                    ),
+                    Operator::Plus,
+                    &col_d,
+                ),
+                binary(
+                    &binary(&col_a, Operator::Plus, &col_b),
+                    Operator::Plus,
+                    &binary(&col_c, Operator::Plus, &col_d),
+                ),
+                true,
+            ),
+            // ((a + b) + c) + d vs a + (b + (c + c)): operands differ
+            (
+                binary(
+                    &binary(
+                        &binary(&col_a, Operator::Plus, &col_b),
+                        Operator::Plus,
+                        &col_c,
+                    ),
+                    Operator::Plus,
+                    &col_d,
+                ),
+                binary(
+                    &col_a,
+                    Operator::Plus,
+                    &binary(
+                        &col_b,
+                        Operator::Plus,
+                        &binary(&col_c, Operator::Plus, &col_c),
+                    ),
+                ),
+                false,
+            ),
+            // (a + b) + c vs a + (b * c): operators differ
+            (
+                binary(
+                    &binary(&col_a, Operator::Plus, &col_b),
+                    Operator::Plus,
+                    &col_c,
+                ),
+                binary(
+                    &col_a,
+                    Operator::Plus,
+                    &binary(&col_b, Operator::Multiply, &col_c),
+                ),
+                false,
+            ),
+            // (a - b) - c vs a - (b - c): subtraction is not associative
+            (
+                binary(
+                    &binary(&col_a, Operator::Minus, &col_b),
+                    Operator::Minus,
+                    &col_c,
+                ),
+                binary(
+                    &col_a,
+                    Operator::Minus,
+                    &binary(&col_b, Operator::Minus, &col_c),
+                ),
+                false,
+            ),
         ];
 
-        for TestCase {
-            left,
-            right,
-            expected,
-            description,
-        } in test_cases
-        {
-            let actual = eq_group.exprs_equal(&left, &right);
+        for (left, right, expected) in test_cases {
             assert_eq!(
-                actual, expected,
-                "{description}: Failed comparing {left:?} and {right:?}, expected {expected}, got {actual}"
+                eq_group.exprs_equal_associative(&left, &right, &schema),
+                expected,
+                "Failed comparing {left} and {right}"
+            );
+            assert_eq!(
+                eq_group.exprs_equal_associative(&right, &left, &schema),
+                expected,
+                "Failed comparing {right} and {left}"
+            );
+        }
+
+        // Strict comparison is unaffected
+        let left = binary(
+            &binary(&col_a, Operator::Plus, &col_b),
+            Operator::Plus,
+            &col_c,
+        );
+        let right = binary(
+            &col_a,
+            Operator::Plus,
+            &binary(&col_b, Operator::Plus, &col_c),
+        );
+        assert!(!eq_group.exprs_equal(&left, &right));
+
+        // Operands are matched regardless of their order:
+        let commuted = binary(
+            &binary(&col_c, Operator::Plus, &col_a),
+            Operator::Plus,
+            &col_b,
+        );
+        assert!(eq_group.exprs_equal_associative(&left, &commuted, &schema));
+        assert!(!eq_group.exprs_equal(&left, &commuted));
+
+        // Only integer and decimal arithmetic can be regrouped:
+        let schema_of = |data_type: DataType| {
+            Schema::new(
+                ["a", "b", "c", "d", "x"]
+                    .map(|name| Field::new(name, data_type.clone(), false))
+                    .to_vec(),
+            )
+        };
+        let test_cases = [
+            (DataType::Int8, true),
+            (DataType::UInt64, true),
+            (DataType::Decimal128(10, 2), true),
+            (DataType::Float32, false),
+            (DataType::Float64, false),
+            (DataType::Interval(IntervalUnit::MonthDayNano), false),
+        ];
+        for (data_type, expected) in test_cases {
+            let schema = schema_of(data_type);
+            assert_eq!(
+                eq_group.exprs_equal_associative(&left, &right, &schema),
+                expected,
+                "{schema}"
             );
         }
 
         Ok(())
     }
 
+    #[test]
+    fn test_orderings_equivalent() -> Result<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+            Field::new("c", DataType::Int32, false),
+            Field::new("d", DataType::Int32, false),
+        ]);
+        let col_a = col("a", &schema)?;
+        let col_b = col("b", &schema)?;
+        let col_c = col("c", &schema)?;
+        let col_d = col("d", &schema)?;
+        let mut group = EquivalenceGroup::default();
+        group.add_equal_conditions(Arc::clone(&col_a), Arc::clone(&col_c));
+        group.add_constant(ConstExpr::from(Arc::clone(&col_d)));
+
+        let asc = SortOptions::default();
+        let ordering = |exprs: &[&Arc<dyn PhysicalExpr>]| {
+            LexOrdering::new(
+                exprs
+                    .iter()
+                    .map(|expr| PhysicalSortExpr::new(Arc::clone(expr), asc)),
+            )
+            .unwrap()
+        };
+
+        // ORDER BY a is equivalent to ORDER BY c, since a = c:
+        assert!(group.orderings_equivalent(&ordering(&[&col_a]), &ordering(&[&col_c])));
+        assert!(group.orderings_equivalent(
+            &ordering(&[&col_c, &col_b]),
+            &ordering(&[&col_a, &col_d, &col_b])
+        ));
+        assert!(group.orderings_equivalent(&ordering(&[&col_d]), &ordering(&[&col_d])));
+        // Unlike satisfaction, equivalence does not allow extra expressions:
+        assert!(
+            !group
+                .orderings_equivalent(&ordering(&[&col_a]), &ordering(&[&col_c, &col_b]))
+        );
+        assert!(!group.orderings_equivalent(&ordering(&[&col_a]), &ordering(&[&col_b])));
+        assert!(!group.orderings_equivalent(&ordering(&[&col_a]), &ordering(&[&col_d])));
+        // Sort options must match:
+        let desc = LexOrdering::new([PhysicalSortExpr::new(Arc::clone(&col_c), !asc)]);
+        assert!(!group.orderings_equivalent(&ordering(&[&col_a]), &desc.unwrap()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_ordering_satisfies() -> Result<()> {
+        let schema = Schema::new(vec![
+            Field::new("x", DataType::Int32, false),
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+            Field::new("c", DataType::Int32, false),
+        ]);
+        let col_x = col("x", &schema)?;
+        let col_a = col("a", &schema)?;
+        let col_b = col("b", &schema)?;
+        let col_c = col("c", &schema)?;
+        let mut group = EquivalenceGroup::default();
+        group.add_equal_conditions(Arc::clone(&col_x), Arc::clone(&col_a));
+        group.add_constant(ConstExpr::from(Arc::clone(&col_c)));
+
+        let asc = SortOptions::default();
+        let desc = !asc;
+        let ordering =
+            |exprs: &[(&Arc<dyn PhysicalExpr>, SortOptions)]| {
+                LexOrdering::new(exprs.iter().map(|(expr, options)| {
+                    PhysicalSortExpr::new(Arc::clone(expr), *options)
+                }))
+                .unwrap()
+            };
+
+        let available = ordering(&[(&col_x, asc), (&col_b, asc)]);
+        let test_cases = [
+            // ORDER BY x satisfies ORDER BY a, since x = a:
+            (ordering(&[(&col_a, asc)]), true),
+            (ordering(&[(&col_a, asc), (&col_b, asc)]), true),
+            // Constants can be skipped:
+            (ordering(&[(&col_c, desc), (&col_a, asc)]), true),
+            (ordering(&[(&col_c, asc)]), true),
+            // Mismatched sort directions:
+            (ordering(&[(&col_a, desc)]), false),
+            (ordering(&[(&col_a, asc), (&col_b, desc)]), false),
+            // Not a prefix:
+            (ordering(&[(&col_b, asc)]), false),
+            (
+                ordering(&[(&col_a, asc), (&col_b, asc), (&col_x, asc)]),
+                true,
+            ),
+            (
+                ordering(&[(&col_a, asc), (&col_b, asc), (&col_c, asc)]),
+                true,
+            ),
+        ];
+        for (required, expected) in test_cases {
+            assert_eq!(
+                group.ordering_satisfies(&available, &required),
+                expected,
+                "available: {available}, required: {required}"
+            );
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_project_classes() -> Result<()> {
         // - columns: [a, b, c].
@@ -1233,4 +4592,550 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_project_ordering() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+            Field::new("c", DataType::Int32, false),
+        ]));
+        let projected_schema =
+            Arc::new(Schema::new(vec![Field::new("a1", DataType::Int32, false)]));
+        let col_a = col("a", &schema)?;
+        let col_b = col("b", &schema)?;
+        let col_c = col("c", &schema)?;
+        let col_a1 = col("a1", &projected_schema)?;
+        let mut group = EquivalenceGroup::default();
+        group.add_equal_conditions(Arc::clone(&col_a), Arc::clone(&col_b));
+
+        let mapping = [(Arc::clone(&col_a), vec![(Arc::clone(&col_a1), 0)].into())]
+            .into_iter()
+            .collect::<ProjectionMapping>();
+        let ordering = |exprs: &[&Arc<dyn PhysicalExpr>]| {
+            LexOrdering::new(exprs.iter().map(|expr| {
+                PhysicalSortExpr::new(Arc::clone(expr), SortOptions::default())
+            }))
+            .unwrap()
+        };
+
+        // ORDER BY a projects to ORDER BY a1:
+        let projected = group.project_ordering(&mapping, &ordering(&[&col_a]));
+        assert_eq!(projected, Some(ordering(&[&col_a1])));
+        // ORDER BY b projects to ORDER BY a1, since a = b:
+        let projected = group.project_ordering(&mapping, &ordering(&[&col_b]));
+        assert_eq!(projected, Some(ordering(&[&col_a1])));
+        // ORDER BY a, c cannot be projected, as c is projected away:
+        let projected = group.project_ordering(&mapping, &ordering(&[&col_a, &col_c]));
+        assert_eq!(projected, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_project_deterministic_order() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+            Field::new("c", DataType::Int32, false),
+            Field::new("d", DataType::Int32, false),
+        ]));
+        let mut group = EquivalenceGroup::default();
+        group.add_equal_conditions(col("c", &schema)?, col("d", &schema)?);
+        group.add_equal_conditions(col("a", &schema)?, col("b", &schema)?);
+
+        let projected_schema = Arc::new(Schema::new(vec![
+            Field::new("a1", DataType::Int32, false),
+            Field::new("a2", DataType::Int32, false),
+            Field::new("c1", DataType::Int32, false),
+            Field::new("c2", DataType::Int32, false),
+        ]));
+        let mapping = [
+            ("a", vec![("a1", 0), ("a2", 1)]),
+            ("c", vec![("c1", 2), ("c2", 3)]),
+        ]
+        .into_iter()
+        .map(|(source, targets)| {
+            let targets = targets
+                .into_iter()
+                .map(|(name, idx)| Ok((col(name, &projected_schema)?, idx)))
+                .collect::<Result<Vec<_>>>()?;
+            Ok((col(source, &schema)?, targets.into()))
+        })
+        .collect::<Result<ProjectionMapping>>()?;
+
+        // Both the mapping and the group iterate in insertion order, so
+        // projecting the same mapping always gives the same result:
+        let projected = group.project(&mapping);
+        assert_eq!(projected.len(), 2);
+        let expected = projected.to_string();
+        for _ in 0..10 {
+            assert_eq!(group.project(&mapping).to_string(), expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_rename_columns() -> Result<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+            Field::new("c", DataType::Int32, false),
+        ]);
+        let col_a = col("a", &schema)?;
+        let col_b = col("b", &schema)?;
+        let col_c = col("c", &schema)?;
+        let a_plus_c = binary(Arc::clone(&col_a), Operator::Plus, col_c, &schema)?;
+        let group = EquivalenceGroup::new([
+            EquivalenceClass::new([Arc::clone(&col_a), Arc::clone(&col_b)]),
+            EquivalenceClass::new([a_plus_c, lit(1)]),
+        ]);
+
+        let renames = HashMap::from([(Column::new("a", 0), Column::new("a_new", 0))]);
+        let renamed = group.rename_columns(&renames);
+        assert_eq!(renamed.len(), 2);
+        let col_a_new = Arc::new(Column::new("a_new", 0)) as Arc<dyn PhysicalExpr>;
+        assert_eq!(
+            renamed[0],
+            EquivalenceClass::new([Arc::clone(&col_a_new), col_b])
+        );
+        // Columns nested inside other expressions are renamed too:
+        assert_eq!(
+            renamed[1].to_string(),
+            "{members: [1, a_new@0 + c@2], constant: (uniform: 1)}"
+        );
+        assert!(!renamed.contains_expr(&col_a));
+        assert!(renamed.contains_expr(&col_a_new));
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_expressions() {
+        let c = |idx| Arc::new(Column::new(&format!("c{idx}"), idx)) as _;
+        let mut group = EquivalenceGroup::new([
+            EquivalenceClass::new([c(0), c(1)]),
+            EquivalenceClass::new([c(2), c(3)]),
+        ]);
+        group.add_constant(ConstExpr::new(c(4), AcrossPartitions::Heterogeneous));
+
+        // Shift all columns by 10:
+        let shifted = group.map_expressions(|expr| {
+            let col = expr.as_any().downcast_ref::<Column>().unwrap();
+            c(col.index() + 10)
+        });
+        assert_eq!(shifted.len(), 3);
+        assert_eq!(shifted[0], EquivalenceClass::new([c(10), c(11)]));
+        assert_eq!(shifted[1], EquivalenceClass::new([c(12), c(13)]));
+        assert_eq!(shifted[2].iter().collect::<Vec<_>>(), vec![&c(14)]);
+        assert_eq!(shifted[2].constant, Some(AcrossPartitions::Heterogeneous));
+
+        // Mapping `c1` and `c2` to the same expression bridges their classes,
+        // whereas mapping `c3` to `c2` makes the second class trivial:
+        let merged = group.map_expressions(|expr| {
+            let col = expr.as_any().downcast_ref::<Column>().unwrap();
+            if col.index() == 1 {
+                c(2)
+            } else {
+                Arc::clone(expr)
+            }
+        });
+        assert_eq!(merged.len(), 2);
+        assert_eq!(merged[0], EquivalenceClass::new([c(0), c(2), c(3)]));
+        let collapsed = EquivalenceGroup::new([EquivalenceClass::new([c(2), c(3)])])
+            .map_expressions(|_| c(2));
+        assert!(collapsed.is_empty());
+    }
+
+    #[test]
+    fn test_group_try_with_offset() -> Result<()> {
+        let c = |idx| Arc::new(Column::new(&format!("c{idx}"), idx)) as _;
+        let group = EquivalenceGroup::new([
+            EquivalenceClass::new([c(0), c(1)]),
+            EquivalenceClass::new([c(2), lit(1)]),
+        ]);
+        let shifted = group.try_with_offset(3)?;
+        let expected = group
+            .iter()
+            .map(|cls| cls.try_with_offset(3))
+            .collect::<Result<Vec<_>>>()?;
+        assert_eq!(shifted.classes, expected);
+        for cls in expected.iter() {
+            for expr in cls.iter() {
+                assert_eq!(shifted.equivalence_class(expr), Some(cls));
+            }
+        }
+        assert!(!shifted.contains_expr(&c(0)));
+
+        // Joins shift the right side by the size of the left side:
+        let joined =
+            EquivalenceGroup::default().join(&group, &JoinType::Inner, 3, &[])?;
+        assert_eq!(joined.classes, expected);
+        assert!(group.try_with_offset(-1).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_join_validates_left_size() -> Result<()> {
+        let c = |idx| Arc::new(Column::new(&format!("c{idx}"), idx)) as _;
+        // Both sides have three columns:
+        let left = EquivalenceGroup::new([EquivalenceClass::new([c(0), c(2)])]);
+        let right = EquivalenceGroup::new([EquivalenceClass::new([c(1), c(2)])]);
+        let on = [(c(1), c(0))];
+
+        let joined = left.try_join(&right, &JoinType::Inner, 3, 6, &on)?;
+        assert_eq!(
+            joined.classes,
+            left.join(&right, &JoinType::Inner, 3, &on)?.classes
+        );
+        assert_eq!(joined.len(), 3);
+
+        // A left size that is too large shifts right side columns past the
+        // end of the output:
+        let err = left
+            .try_join(&right, &JoinType::Inner, 4, 6, &on)
+            .unwrap_err();
+        assert_contains!(
+            err.to_string(),
+            "Right side column index 2 is out of bounds"
+        );
+        // A left size that is too small makes left side columns overlap with
+        // right side columns:
+        let err = left
+            .try_join(&right, &JoinType::Right, 2, 6, &on)
+            .unwrap_err();
+        assert_contains!(err.to_string(), "Left side column index 2 is out of bounds");
+
+        // Sizes are irrelevant for joins that do not concatenate both sides:
+        left.try_join(&right, &JoinType::LeftSemi, 2, 3, &on)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_join_mark() -> Result<()> {
+        let c = |idx| Arc::new(Column::new(&format!("c{idx}"), idx)) as _;
+        let left = EquivalenceGroup::new([EquivalenceClass::new([c(0), c(1)])]);
+        let right = EquivalenceGroup::new([EquivalenceClass::new([c(1), c(2)])]);
+        let on = [(c(0), c(0))];
+
+        // The left side has two columns, so the mark column is at index 2:
+        let joined = left.join(&right, &JoinType::LeftMark, 2, &on)?;
+        assert_eq!(
+            joined.iter().collect::<Vec<_>>(),
+            left.iter().collect::<Vec<_>>()
+        );
+        let mark = Arc::new(Column::new("mark", 2)) as _;
+        assert!(!joined.contains_expr(&mark));
+        assert!(!joined.contains_expr(&c(2)));
+
+        // The right side has three columns, so the mark column is at index 3
+        // and right side columns keep their indices:
+        let joined = left.join(&right, &JoinType::RightMark, 2, &on)?;
+        assert_eq!(
+            joined.iter().collect::<Vec<_>>(),
+            right.iter().collect::<Vec<_>>()
+        );
+        assert!(joined.contains_expr(&c(2)));
+        assert!(!joined.contains_expr(&(Arc::new(Column::new("mark", 3)) as _)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_join_semi_on_equalities() -> Result<()> {
+        let c = |idx| Arc::new(Column::new(&format!("c{idx}"), idx)) as _;
+        let left = EquivalenceGroup::new([EquivalenceClass::new([c(0), c(1)])]);
+        let right = EquivalenceGroup::new([EquivalenceClass::new([c(4), c(5)])]);
+        // Since c0 = c1 on the left, surviving right rows have c0 = c2. Also,
+        // c3 is joined with both c3 and c7 on the right, so c3 = c7. Likewise,
+        // surviving left rows have c4 = c5 since both are joined with c6:
+        let on = [
+            (c(0), c(0)),
+            (c(1), c(2)),
+            (c(3), c(3)),
+            (c(4), c(6)),
+            (c(5), c(6)),
+            (c(3), c(7)),
+        ];
+
+        let joined = left.join(&right, &JoinType::RightSemi, 2, &on)?;
+        assert_eq!(joined.len(), 3);
+        assert!(joined.exprs_equal(&c(0), &c(2)));
+        assert!(joined.exprs_equal(&c(3), &c(7)));
+        assert!(joined.exprs_equal(&c(4), &c(5)));
+        assert!(!joined.exprs_equal(&c(0), &c(3)));
+        assert!(!joined.exprs_equal(&c(3), &c(6)));
+
+        let joined = left.join(&right, &JoinType::LeftSemi, 2, &on)?;
+        assert_eq!(joined.len(), 2);
+        assert_eq!(joined[0], EquivalenceClass::new([c(0), c(1)]));
+        assert_eq!(joined[1], EquivalenceClass::new([c(4), c(5)]));
+
+        // Anti joins do not gain any equalities:
+        let joined = left.join(&right, &JoinType::RightAnti, 2, &on)?;
+        assert_eq!(joined.classes, right.classes);
+        let joined = left.join(&right, &JoinType::LeftAnti, 2, &on)?;
+        assert_eq!(joined.classes, left.classes);
+        Ok(())
+    }
+
+    #[test]
+    fn test_join_then_project() -> Result<()> {
+        let left_schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+        ]);
+        let right_schema = Schema::new(vec![
+            Field::new("x", DataType::Int32, false),
+            Field::new("y", DataType::Int32, false),
+        ]);
+        let joined_schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+            Field::new("x", DataType::Int32, false),
+            Field::new("y", DataType::Int32, false),
+        ]);
+        // Inner join on a = x:
+        let on = [(col("a", &left_schema)?, col("x", &right_schema)?)];
+        let joined = EquivalenceGroup::default().join(
+            &EquivalenceGroup::default(),
+            &JoinType::Inner,
+            left_schema.fields().len(),
+            &on,
+        )?;
+        let expected =
+            EquivalenceClass::new([col("a", &joined_schema)?, col("x", &joined_schema)?]);
+        assert_eq!(joined.iter().collect::<Vec<_>>(), vec![&expected]);
+
+        // Projecting both join keys (in reverse order) keeps the equivalence:
+        let projected_schema = Schema::new(vec![
+            Field::new("x", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+            Field::new("a", DataType::Int32, false),
+        ]);
+        let mapping = ["x", "b", "a"]
+            .into_iter()
+            .enumerate()
+            .map(|(idx, name)| {
+                let source = col(name, &joined_schema)?;
+                let target = col(name, &projected_schema)?;
+                Ok((source, vec![(target, idx)].into()))
+            })
+            .collect::<Result<ProjectionMapping>>()?;
+        let projected = joined.project(&mapping);
+        let expected = EquivalenceClass::new([
+            col("a", &projected_schema)?,
+            col("x", &projected_schema)?,
+        ]);
+        assert_eq!(projected.iter().collect::<Vec<_>>(), vec![&expected]);
+        assert!(
+            projected.exprs_equal(
+                &col("a", &projected_schema)?,
+                &col("x", &projected_schema)?
+            )
+        );
+
+        // Projecting away one of the join keys drops the equivalence:
+        let projected_schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("y", DataType::Int32, false),
+        ]);
+        let mapping = ["a", "y"]
+            .into_iter()
+            .enumerate()
+            .map(|(idx, name)| {
+                let source = col(name, &joined_schema)?;
+                let target = col(name, &projected_schema)?;
+                Ok((source, vec![(target, idx)].into()))
+            })
+            .collect::<Result<ProjectionMapping>>()?;
+        assert!(joined.project(&mapping).is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_project_case_expr() -> Result<()> {
+        // - columns: [a, b, c, d].
+        // - "c" and "d" in the same equivalence class.
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+            Field::new("c", DataType::Int32, false),
+            Field::new("d", DataType::Int32, false),
+        ]);
+        let mut group = EquivalenceGroup::default();
+        group.add_equal_conditions(col("c", &schema)?, col("d", &schema)?);
+
+        // CASE WHEN a = 1 THEN b ELSE d END
+        let case_expr = |a, b, d| {
+            case(
+                None,
+                vec![(binary(a, Operator::Eq, lit(1), &schema)?, b)],
+                Some(d),
+            )
+        };
+        let expr = case_expr(col("a", &schema)?, col("b", &schema)?, col("d", &schema)?)?;
+
+        // Projection: a, b as b1, c
+        let projected_schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b1", DataType::Int32, false),
+            Field::new("c", DataType::Int32, false),
+        ]);
+        let entries = [
+            (col("a", &schema)?, col("a", &projected_schema)?, 0),
+            (col("b", &schema)?, col("b1", &projected_schema)?, 1),
+            (col("c", &schema)?, col("c", &projected_schema)?, 2),
+        ];
+        let to_mapping = |entries: &[_]| {
+            entries
+                .iter()
+                .cloned()
+                .map(|(source, target, idx)| (source, vec![(target, idx)].into()))
+                .collect::<ProjectionMapping>()
+        };
+        let mapping = to_mapping(&entries);
+
+        // The renamed branch column and the equivalent `ELSE` column are both
+        // projected:
+        let expected = case_expr(
+            col("a", &projected_schema)?,
+            col("b1", &projected_schema)?,
+            col("c", &projected_schema)?,
+        )?;
+        let projected = group.project_expr(&mapping, &expr);
+        assert_eq!(projected, Some(expected));
+
+        // If a branch column is projected away, the expression can not be
+        // projected:
+        let mapping = to_mapping(&[entries[0].clone(), entries[2].clone()]);
+        assert_eq!(group.project_expr(&mapping, &expr), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_project_expr_all() -> Result<()> {
+        // - columns: [a, b, c].
+        // - "a" and "b" in the same equivalence class.
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+            Field::new("c", DataType::Int32, false),
+        ]);
+        let mut group = EquivalenceGroup::default();
+        group.add_equal_conditions(col("a", &schema)?, col("b", &schema)?);
+
+        // Projection: a, a as a2, c
+        let projected_schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("a2", DataType::Int32, false),
+            Field::new("c", DataType::Int32, false),
+        ]);
+        let mapping = [
+            (
+                col("a", &schema)?,
+                vec![
+                    (col("a", &projected_schema)?, 0),
+                    (col("a2", &projected_schema)?, 1),
+                ]
+                .into(),
+            ),
+            (
+                col("c", &schema)?,
+                vec![(col("c", &projected_schema)?, 2)].into(),
+            ),
+        ]
+        .into_iter()
+        .collect::<ProjectionMapping>();
+
+        let expected = vec![col("a", &projected_schema)?, col("a2", &projected_schema)?];
+        // Direct match:
+        assert_eq!(
+            group.project_expr_all(&mapping, &col("a", &schema)?),
+            expected
+        );
+        // Match through equivalence:
+        assert_eq!(
+            group.project_expr_all(&mapping, &col("b", &schema)?),
+            expected
+        );
+        assert_eq!(
+            group.project_expr_all(&mapping, &col("c", &schema)?),
+            vec![col("c", &projected_schema)?]
+        );
+        // No match:
+        let mapping = [(
+            col("c", &schema)?,
+            vec![(col("c", &projected_schema)?, 0)].into(),
+        )]
+        .into_iter()
+        .collect::<ProjectionMapping>();
+        assert!(
+            group
+                .project_expr_all(&mapping, &col("a", &schema)?)
+                .is_empty()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_project_direct_match_precedence() -> Result<()> {
+        // - columns: [a, b, c].
+        // - "a" and "b" in the same equivalence class.
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+            Field::new("c", DataType::Int32, false),
+        ]));
+        let projected_schema = Arc::new(Schema::new(vec![
+            Field::new("a1", DataType::Int32, false),
+            Field::new("b1", DataType::Int32, false),
+        ]));
+        let col_a = col("a", &schema)?;
+        let col_b = col("b", &schema)?;
+        let col_a1 = col("a1", &projected_schema)?;
+        let col_b1 = col("b1", &projected_schema)?;
+        let mut group = EquivalenceGroup::default();
+        group.add_equal_conditions(Arc::clone(&col_a), Arc::clone(&col_b));
+
+        // Only "a" is mapped: "a" projects directly and "b" projects through
+        // its equivalence with "a", so both resolve to "a1":
+        let mapping = [(Arc::clone(&col_a), vec![(Arc::clone(&col_a1), 0)].into())]
+            .into_iter()
+            .collect::<ProjectionMapping>();
+        assert_eq!(
+            group.project_expr(&mapping, &col_a),
+            Some(Arc::clone(&col_a1))
+        );
+        assert_eq!(
+            group.project_expr(&mapping, &col_b),
+            Some(Arc::clone(&col_a1))
+        );
+        // The projected class `[a1]` is trivial, so it is not retained:
+        let projected = group.project(&mapping);
+        assert!(projected.is_empty());
+
+        // Both "a" and "b" are mapped: Direct matches take precedence over
+        // equivalence matches, so "b" projects to "b1":
+        let mapping = [
+            (Arc::clone(&col_a), vec![(Arc::clone(&col_a1), 0)].into()),
+            (Arc::clone(&col_b), vec![(Arc::clone(&col_b1), 1)].into()),
+        ]
+        .into_iter()
+        .collect::<ProjectionMapping>();
+        assert_eq!(
+            group.project_expr(&mapping, &col_a),
+            Some(Arc::clone(&col_a1))
+        );
+        assert_eq!(
+            group.project_expr(&mapping, &col_b),
+            Some(Arc::clone(&col_b1))
+        );
+        // The projected class is `[a1, b1]`:
+        let projected = group.project(&mapping);
+        assert_eq!(projected.len(), 1);
+        assert_eq!(
+            projected.iter().next().unwrap(),
+            &EquivalenceClass::new([col_a1, col_b1])
+        );
+
+        Ok(())
+    }
 }
@@ -27,7 +27,10 @@ mod class;
 mod ordering;
 mod properties;
 
-pub use class::{AcrossPartitions, ConstExpr, EquivalenceClass, EquivalenceGroup};
+pub use class::{
+    AcrossPartitions, ConstExpr, ConstExprSet, EquivalenceClass, EquivalenceGroup,
+    NormalizerCache, PartitionValues,
+};
 pub use ordering::OrderingEquivalenceClass;
 // Re-export for backwards compatibility, we recommend importing from
 // datafusion_physical_expr::projection instead
@@ -428,7 +428,7 @@ impl EquivalenceProperties {
         };
         let eq_class = self
             .eq_group
-            .get_equivalence_class(&normal_expr)
+            .equivalence_class(&normal_expr)
             .map_or_else(|| vec![normal_expr], |class| class.clone().into());
 
         let mut new_orderings = vec![];
@@ -1292,6 +1292,7 @@ impl EquivalenceProperties {
                 .into_iter()
                 .map(|expr| with_new_schema(expr, &schema))
                 .collect::<Result<_>>()?;
+            eq_class.update_canonical();
             // Rewrite the constant value (if available and known):
             let data_type = eq_class
                 .canonical_expr()
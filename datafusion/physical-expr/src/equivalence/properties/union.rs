@@ -19,7 +19,6 @@ use std::iter::Peekable;
 use std::sync::Arc;
 
 use super::EquivalenceProperties;
-use crate::equivalence::class::AcrossPartitions;
 use crate::{ConstExpr, PhysicalSortExpr};
 
 use arrow::datatypes::SchemaRef;
@@ -52,18 +51,11 @@ fn calculate_union_binary(
         .into_iter()
         .filter_map(|lhs_const| {
             // Find matching constant expression in RHS
+            // If both sides have matching constant values, preserve it.
+            // Otherwise, fall back to heterogeneous values.
             rhs_constants
                 .iter()
-                .find(|rhs_const| rhs_const.expr.eq(&lhs_const.expr))
-                .map(|rhs_const| {
-                    let mut const_expr = lhs_const.clone();
-                    // If both sides have matching constant values, preserve it.
-                    // Otherwise, set fall back to heterogeneous values.
-                    if lhs_const.across_partitions != rhs_const.across_partitions {
-                        const_expr.across_partitions = AcrossPartitions::Heterogeneous;
-                    }
-                    const_expr
-                })
+                .find_map(|rhs_const| lhs_const.try_merge(rhs_const))
         })
         .collect::<Vec<_>>();
 
@@ -308,6 +300,7 @@ fn advance_if_matches_constant<'a>(
 mod tests {
     use super::*;
     use crate::PhysicalExpr;
+    use crate::equivalence::class::AcrossPartitions;
     use crate::equivalence::tests::{create_test_schema, parse_sort_expr};
     use crate::expressions::col;
 
@@ -892,7 +885,7 @@ mod tests {
         let const_a = &union_props.constants()[0];
         assert!(const_a.expr.eq(&col_a));
         assert_eq!(
-            const_a.across_partitions,
+            *const_a.partition_values(),
             AcrossPartitions::Uniform(Some(literal_10))
         );
 
@@ -968,13 +961,13 @@ mod tests {
         let union_const_1 = &union_props.constants()[0];
         assert!(union_const_1.expr.eq(&col_const_1));
         assert_eq!(
-            union_const_1.across_partitions,
+            *union_const_1.partition_values(),
             AcrossPartitions::Uniform(Some(literal_foo)),
         );
         let union_const_2 = &union_props.constants()[1];
         assert!(union_const_2.expr.eq(&col_const_2));
         assert_eq!(
-            union_const_2.across_partitions,
+            *union_const_2.partition_values(),
             AcrossPartitions::Heterogeneous,
         );
 
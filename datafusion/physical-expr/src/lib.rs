@@ -56,7 +56,7 @@ pub mod execution_props {
 pub use aggregate::groups_accumulator::{GroupsAccumulatorAdapter, NullState};
 pub use analysis::{AnalysisContext, ExprBoundaries, analyze};
 pub use equivalence::{
-    AcrossPartitions, ConstExpr, EquivalenceProperties, calculate_union,
+    AcrossPartitions, ConstExpr, EquivalenceProperties, PartitionValues, calculate_union,
 };
 pub use partitioning::{Distribution, Partitioning};
 pub use physical_expr::{
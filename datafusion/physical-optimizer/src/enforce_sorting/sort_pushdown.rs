@@ -531,7 +531,7 @@ fn expr_source_side(
             let (mut valid_left, mut valid_right) = (true, true);
             for (left, right) in ordering.iter_mut().zip(right_ordering.iter_mut()) {
                 let col = left.expr.as_any().downcast_ref::<Column>()?;
-                let eq_class = eq_group.get_equivalence_class(&left.expr);
+                let eq_class = eq_group.equivalence_class(&left.expr);
                 if col.index() < left_columns_len {
                     if valid_right {
                         valid_right = eq_class.is_some_and(|cls| {
@@ -31,10 +31,10 @@ use crate::{
 
 use arrow::datatypes::{Schema, SchemaRef};
 use arrow_schema::{FieldRef, SortOptions};
-use datafusion_common::{Result, exec_err};
+use datafusion_common::{Result, exec_err, not_impl_err};
 use datafusion_expr::{
     LimitEffect, PartitionEvaluator, ReversedUDWF, SetMonotonicity, WindowFrame,
-    WindowFunctionDefinition, WindowUDF,
+    WindowFrameExclusion, WindowFunctionDefinition, WindowUDF,
 };
 use datafusion_functions_window_common::expr::ExpressionArgs;
 use datafusion_functions_window_common::field::WindowUDFFieldArgs;
@@ -101,6 +101,12 @@ pub fn create_window_expr(
     distinct: bool,
     filter: Option<Arc<dyn PhysicalExpr>>,
 ) -> Result<Arc<dyn WindowExpr>> {
+    if window_frame.exclusion != WindowFrameExclusion::NoOthers {
+        return not_impl_err!(
+            "Window frame exclusion is not supported: EXCLUDE {}",
+            window_frame.exclusion
+        );
+    }
     Ok(match fun {
         WindowFunctionDefinition::AggregateUDF(fun) => {
             let aggregate = if distinct {
@@ -745,6 +751,7 @@ mod tests {
     use InputOrderMode::{Linear, PartiallySorted, Sorted};
     use arrow::compute::SortOptions;
     use arrow_schema::{DataType, Field};
+    use datafusion_common::assert_contains;
     use datafusion_execution::TaskContext;
     use datafusion_functions_aggregate::count::count_udaf;
 
@@ -925,6 +932,31 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_create_window_expr_rejects_exclusion() -> Result<()> {
+        let schema = create_test_schema()?;
+        let frame =
+            WindowFrame::new(Some(false)).with_exclusion(WindowFrameExclusion::Ties);
+        let err = create_window_expr(
+            &WindowFunctionDefinition::AggregateUDF(count_udaf()),
+            "count".to_owned(),
+            &[col("nullable_col", &schema)?],
+            &[],
+            &[sort_expr("nullable_col", &schema)],
+            Arc::new(frame),
+            schema,
+            false,
+            false,
+            None,
+        )
+        .unwrap_err();
+        assert_contains!(
+            err.to_string(),
+            "Window frame exclusion is not supported: EXCLUDE TIES"
+        );
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_satisfy_nullable() -> Result<()> {
         let schema = create_test_schema()?;
@@ -1560,6 +1560,35 @@ pub struct PhysicalSortExprNodeCollection {
     #[prost(message, repeated, tag = "1")]
     pub physical_sort_expr_nodes: ::prost::alloc::vec::Vec<PhysicalSortExprNode>,
 }
+/// Whether a constant expression has the same value across all partitions
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PhysicalAcrossPartitionsNode {
+    #[prost(bool, tag = "1")]
+    pub uniform: bool,
+    /// The value of a uniform constant, if known
+    #[prost(message, optional, tag = "2")]
+    pub value: ::core::option::Option<super::datafusion_common::ScalarValue>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PhysicalConstExprNode {
+    #[prost(message, optional, tag = "1")]
+    pub expr: ::core::option::Option<PhysicalExprNode>,
+    #[prost(message, optional, tag = "2")]
+    pub across_partitions: ::core::option::Option<PhysicalAcrossPartitionsNode>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PhysicalEquivalenceClassNode {
+    #[prost(message, repeated, tag = "1")]
+    pub exprs: ::prost::alloc::vec::Vec<PhysicalExprNode>,
+    /// Set if the expressions of this class are constant
+    #[prost(message, optional, tag = "2")]
+    pub constant: ::core::option::Option<PhysicalAcrossPartitionsNode>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PhysicalEquivalenceGroupNode {
+    #[prost(message, repeated, tag = "1")]
+    pub classes: ::prost::alloc::vec::Vec<PhysicalEquivalenceClassNode>,
+}
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ProjectionExpr {
     #[prost(string, tag = "1")]
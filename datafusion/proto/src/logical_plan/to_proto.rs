@@ -30,8 +30,8 @@ use datafusion_expr::expr::{
 };
 use datafusion_expr::{
     Expr, JoinConstraint, JoinType, SortExpr, TryCast, WindowFrame, WindowFrameBound,
-    WindowFrameUnits, WindowFunctionDefinition, logical_plan::PlanType,
-    logical_plan::StringifiedPlan,
+    WindowFrameExclusion, WindowFrameUnits, WindowFunctionDefinition,
+    logical_plan::PlanType, logical_plan::StringifiedPlan,
 };
 
 use crate::protobuf::RecursionUnnestOption;
@@ -164,6 +164,12 @@ impl TryFrom<&WindowFrame> for protobuf::WindowFrame {
     type Error = Error;
 
     fn try_from(window: &WindowFrame) -> Result<Self, Self::Error> {
+        if window.exclusion != WindowFrameExclusion::NoOthers {
+            return Err(Error::NotImplemented(format!(
+                "Proto serialization error: window frame exclusion EXCLUDE {} not supported",
+                window.exclusion
+            )));
+        }
         Ok(Self {
             window_frame_units: protobuf::WindowFrameUnits::from(window.units).into(),
             start_bound: Some((&window.start_bound).try_into()?),
@@ -42,8 +42,11 @@ use datafusion_datasource_parquet::file_format::ParquetSink;
 use datafusion_execution::object_store::ObjectStoreUrl;
 use datafusion_execution::{FunctionRegistry, TaskContext};
 use datafusion_expr::WindowFunctionDefinition;
+use datafusion_physical_expr::equivalence::{EquivalenceClass, EquivalenceGroup};
 use datafusion_physical_expr::projection::{ProjectionExpr, ProjectionExprs};
-use datafusion_physical_expr::{LexOrdering, PhysicalSortExpr, ScalarFunctionExpr};
+use datafusion_physical_expr::{
+    AcrossPartitions, ConstExpr, LexOrdering, PhysicalSortExpr, ScalarFunctionExpr,
+};
 use datafusion_physical_plan::expressions::{
     BinaryExpr, CaseExpr, CastExpr, Column, IsNotNullExpr, IsNullExpr, LikeExpr, Literal,
     NegativeExpr, NotExpr, TryCastExpr, UnKnownColumn, in_list,
@@ -114,6 +117,99 @@ pub fn parse_physical_sort_exprs(
         .collect()
 }
 
+/// Parses an [`AcrossPartitions`] value from a protobuf.
+pub fn parse_across_partitions(
+    proto: &protobuf::PhysicalAcrossPartitionsNode,
+) -> Result<AcrossPartitions> {
+    if proto.uniform {
+        let value = proto
+            .value
+            .as_ref()
+            .map(|value| value.try_into())
+            .transpose()?;
+        Ok(AcrossPartitions::Uniform(value))
+    } else {
+        Ok(AcrossPartitions::Heterogeneous)
+    }
+}
+
+/// Parses a [`ConstExpr`] from a protobuf.
+///
+/// # Arguments
+///
+/// * `proto` - Input proto with constant expression node
+/// * `ctx` - The task context, used to look up user-defined functions
+/// * `input_schema` - The Arrow schema for the input, used for determining expression data types
+///   when performing type coercion.
+/// * `codec` - An extension codec used to decode custom UDFs.
+pub fn parse_const_expr(
+    proto: &protobuf::PhysicalConstExprNode,
+    ctx: &TaskContext,
+    input_schema: &Schema,
+    codec: &dyn PhysicalExtensionCodec,
+) -> Result<ConstExpr> {
+    let expr = parse_required_physical_expr(
+        proto.expr.as_ref(),
+        ctx,
+        "expr",
+        input_schema,
+        codec,
+    )?;
+    let across_partitions = proto
+        .across_partitions
+        .as_ref()
+        .map(parse_across_partitions)
+        .transpose()?
+        .unwrap_or_default();
+    Ok(ConstExpr::new(expr, across_partitions))
+}
+
+/// Parses an [`EquivalenceClass`] from a protobuf.
+///
+/// # Arguments
+///
+/// * `proto` - Input proto with equivalence class node
+/// * `ctx` - The task context, used to look up user-defined functions
+/// * `input_schema` - The Arrow schema for the input, used for determining expression data types
+///   when performing type coercion.
+/// * `codec` - An extension codec used to decode custom UDFs.
+pub fn parse_equivalence_class(
+    proto: &protobuf::PhysicalEquivalenceClassNode,
+    ctx: &TaskContext,
+    input_schema: &Schema,
+    codec: &dyn PhysicalExtensionCodec,
+) -> Result<EquivalenceClass> {
+    let exprs = parse_physical_exprs(&proto.exprs, ctx, input_schema, codec)?;
+    let class = EquivalenceClass::new(exprs);
+    Ok(match &proto.constant {
+        Some(constant) => class.with_constant(parse_across_partitions(constant)?),
+        None => class,
+    })
+}
+
+/// Parses an [`EquivalenceGroup`] from a protobuf.
+///
+/// # Arguments
+///
+/// * `proto` - Input proto with equivalence group node
+/// * `ctx` - The task context, used to look up user-defined functions
+/// * `input_schema` - The Arrow schema for the input, used for determining expression data types
+///   when performing type coercion.
+/// * `codec` - An extension codec used to decode custom UDFs.
+pub fn parse_equivalence_group(
+    proto: &protobuf::PhysicalEquivalenceGroupNode,
+    ctx: &TaskContext,
+    input_schema: &Schema,
+    codec: &dyn PhysicalExtensionCodec,
+) -> Result<EquivalenceGroup> {
+    let classes = proto
+        .classes
+        .iter()
+        .map(|class| parse_equivalence_class(class, ctx, input_schema, codec))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(EquivalenceGroup::new(classes))
+}
+
 /// Parses a physical window expr from a protobuf.
 ///
 /// # Arguments
@@ -793,4 +889,67 @@ mod tests {
         let err = PartitionedFile::try_from(&proto).unwrap_err();
         assert!(err.to_string().contains("Invalid object_store path"));
     }
+
+    #[test]
+    fn equivalence_group_roundtrip() -> Result<()> {
+        use super::super::to_proto::serialize_equivalence_group;
+        use crate::physical_plan::DefaultPhysicalExtensionCodec;
+        use arrow::datatypes::{DataType, Field};
+        use datafusion_common::ScalarValue;
+        use datafusion_physical_expr::expressions::{Column, lit};
+
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+            Field::new("c", DataType::Int32, false),
+            Field::new("d", DataType::Int32, false),
+        ]);
+        let col = |name: &str| -> Arc<dyn PhysicalExpr> {
+            Arc::new(Column::new_with_schema(name, &schema).unwrap())
+        };
+        let group = EquivalenceGroup::new([
+            EquivalenceClass::new([col("a"), col("b")]).with_constant(
+                AcrossPartitions::Uniform(Some(ScalarValue::Int32(Some(5)))),
+            ),
+            EquivalenceClass::new([col("c"), lit(ScalarValue::Int32(Some(1)))]),
+            EquivalenceClass::new([col("d"), col("a")])
+                .with_constant(AcrossPartitions::Heterogeneous),
+        ]);
+
+        let ctx = TaskContext::default();
+        let codec = DefaultPhysicalExtensionCodec {};
+        let proto = serialize_equivalence_group(&group, &codec)?;
+        let parsed = parse_equivalence_group(&proto, &ctx, &schema, &codec)?;
+        assert_eq!(
+            parsed.iter().collect::<Vec<_>>(),
+            group.iter().collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn const_expr_roundtrip() -> Result<()> {
+        use super::super::to_proto::serialize_const_expr;
+        use crate::physical_plan::DefaultPhysicalExtensionCodec;
+        use arrow::datatypes::{DataType, Field};
+        use datafusion_common::ScalarValue;
+        use datafusion_physical_expr::expressions::Column;
+
+        let schema = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
+        let col: Arc<dyn PhysicalExpr> = Arc::new(Column::new("a", 0));
+        let ctx = TaskContext::default();
+        let codec = DefaultPhysicalExtensionCodec {};
+        for across_partitions in [
+            AcrossPartitions::Heterogeneous,
+            AcrossPartitions::Uniform(None),
+            AcrossPartitions::Uniform(Some(ScalarValue::Int32(Some(5)))),
+        ] {
+            let const_expr = ConstExpr::new(Arc::clone(&col), across_partitions.clone());
+            let proto = serialize_const_expr(&const_expr, &codec)?;
+            let parsed = parse_const_expr(&proto, &ctx, &schema, &codec)?;
+            assert!(parsed.expr.eq(&col));
+            assert_eq!(*parsed.partition_values(), across_partitions);
+        }
+        Ok(())
+    }
 }
@@ -32,8 +32,9 @@ use datafusion_datasource_json::file_format::JsonSink;
 #[cfg(feature = "parquet")]
 use datafusion_datasource_parquet::file_format::ParquetSink;
 use datafusion_expr::WindowFrame;
-use datafusion_physical_expr::ScalarFunctionExpr;
+use datafusion_physical_expr::equivalence::{EquivalenceClass, EquivalenceGroup};
 use datafusion_physical_expr::window::{SlidingAggregateWindowExpr, StandardWindowExpr};
+use datafusion_physical_expr::{AcrossPartitions, ConstExpr, ScalarFunctionExpr};
 use datafusion_physical_expr_common::physical_expr::snapshot_physical_expr;
 use datafusion_physical_expr_common::sort_expr::PhysicalSortExpr;
 use datafusion_physical_plan::expressions::LikeExpr;
@@ -202,6 +203,64 @@ pub fn serialize_physical_sort_expr(
     })
 }
 
+/// Serialize an [`AcrossPartitions`] value to its protobuf representation.
+pub fn serialize_across_partitions(
+    across_partitions: &AcrossPartitions,
+) -> Result<protobuf::PhysicalAcrossPartitionsNode> {
+    Ok(match across_partitions {
+        AcrossPartitions::Heterogeneous => protobuf::PhysicalAcrossPartitionsNode {
+            uniform: false,
+            value: None,
+        },
+        AcrossPartitions::Uniform(value) => protobuf::PhysicalAcrossPartitionsNode {
+            uniform: true,
+            value: value.as_ref().map(|value| value.try_into()).transpose()?,
+        },
+    })
+}
+
+/// Serialize a [`ConstExpr`] to its protobuf representation. Only whether the
+/// constant is uniform across partitions (and its value, if so) is serialized;
+/// any per-partition values are not.
+pub fn serialize_const_expr(
+    const_expr: &ConstExpr,
+    codec: &dyn PhysicalExtensionCodec,
+) -> Result<protobuf::PhysicalConstExprNode> {
+    Ok(protobuf::PhysicalConstExprNode {
+        expr: Some(serialize_physical_expr(&const_expr.expr, codec)?),
+        across_partitions: Some(serialize_across_partitions(&AcrossPartitions::from(
+            const_expr.partition_values(),
+        ))?),
+    })
+}
+
+/// Serialize an [`EquivalenceClass`] to its protobuf representation.
+pub fn serialize_equivalence_class(
+    class: &EquivalenceClass,
+    codec: &dyn PhysicalExtensionCodec,
+) -> Result<protobuf::PhysicalEquivalenceClassNode> {
+    Ok(protobuf::PhysicalEquivalenceClassNode {
+        exprs: serialize_physical_exprs(class.iter(), codec)?,
+        constant: class
+            .constant()
+            .map(serialize_across_partitions)
+            .transpose()?,
+    })
+}
+
+/// Serialize an [`EquivalenceGroup`] to its protobuf representation.
+pub fn serialize_equivalence_group(
+    group: &EquivalenceGroup,
+    codec: &dyn PhysicalExtensionCodec,
+) -> Result<protobuf::PhysicalEquivalenceGroupNode> {
+    Ok(protobuf::PhysicalEquivalenceGroupNode {
+        classes: group
+            .iter()
+            .map(|class| serialize_equivalence_class(class, codec))
+            .collect::<Result<_>>()?,
+    })
+}
+
 pub fn serialize_physical_exprs<'a, I>(
     values: I,
     codec: &dyn PhysicalExtensionCodec,
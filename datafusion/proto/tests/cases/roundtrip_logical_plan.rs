@@ -82,7 +82,8 @@ use datafusion_expr::{
     Accumulator, AggregateUDF, ColumnarValue, ExprFunctionExt, ExprSchemable,
     LimitEffect, Literal, LogicalPlan, LogicalPlanBuilder, Operator, PartitionEvaluator,
     ScalarUDF, Signature, TryCast, Volatility, WindowFrame, WindowFrameBound,
-    WindowFrameUnits, WindowFunctionDefinition, WindowUDF, WindowUDFImpl,
+    WindowFrameExclusion, WindowFrameUnits, WindowFunctionDefinition, WindowUDF,
+    WindowUDFImpl,
 };
 use datafusion_functions_aggregate::average::avg_udaf;
 use datafusion_functions_aggregate::expr_fn::{
@@ -2704,6 +2705,26 @@ fn roundtrip_window() {
     roundtrip_expr_test(test_expr11, ctx);
 }
 
+#[test]
+fn window_frame_exclusion_not_serialized() {
+    let frame = WindowFrame::new(Some(false)).with_exclusion(WindowFrameExclusion::Ties);
+    let test_expr = Expr::from(expr::WindowFunction::new(
+        WindowFunctionDefinition::AggregateUDF(max_udaf()),
+        vec![col("col1")],
+    ))
+    .order_by(vec![col("col2").sort(true, true)])
+    .window_frame(frame)
+    .build()
+    .unwrap();
+
+    let err = serialize_expr(&test_expr, &DefaultLogicalExtensionCodec {}).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("window frame exclusion EXCLUDE TIES not supported"),
+        "{err}"
+    );
+}
+
 #[tokio::test]
 async fn roundtrip_recursive_query() {
     let query = "WITH RECURSIVE cte AS (
@@ -2763,6 +2763,16 @@ fn over_partition_by() {
     );
 }
 
+#[test]
+fn over_order_by_with_window_frame_exclusion() {
+    let sql = "SELECT order_id, MAX(qty) OVER (ORDER BY order_id ROWS BETWEEN 1 PRECEDING AND CURRENT ROW EXCLUDE TIES) from orders";
+    let err = logical_plan(sql).unwrap_err().strip_backtrace();
+    assert_snapshot!(
+        err,
+        @r#"SQL error: ParserError("Expected: ), found: EXCLUDE at Line: 1, Column: 92")"#
+    );
+}
+
 /// psql result
 /// ```text
 ///                                     QUERY PLAN